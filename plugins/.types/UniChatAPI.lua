@@ -15,11 +15,37 @@
 ---@field fetch_shared_emotes fun(self: UniChatAPI, platform: string, channel_id: string)
 ---@field get_shared_emotes fun(self: UniChatAPI): table<string, UniChatEmote>
 ---@field expose_module fun(self: UniChatAPI, module_name: string, module_table: table)
----@field add_event_listener fun(self: UniChatAPI, callback: function): number
+---@field add_event_listener fun(self: UniChatAPI, callback: function, filter?: UniChatEventListenerFilter): number
 ---@field remove_event_listener fun(self: UniChatAPI, listener_id: number)
+---@field emit fun(self: UniChatAPI, event: UniChatEvent)
+---@field add_event_interceptor fun(self: UniChatAPI, callback: fun(event: table): UniChatEvent | table | nil, priority?: integer): integer
+---@field remove_event_interceptor fun(self: UniChatAPI, interceptor_id: integer)
+---@field register_rpc_method fun(self: UniChatAPI, method_name: string, callback: fun(...: any): any)
+---@field register_http_route fun(self: UniChatAPI, method: string, path: string, handler: fun(request: UniChatHttpRouteRequest): UniChatHttpRouteResponse?)
+---@field set_timeout fun(self: UniChatAPI, callback: fun(), delay_ms: integer): integer
+---@field set_interval fun(self: UniChatAPI, callback: fun(), interval_ms: integer): integer
+---@field clear_timer fun(self: UniChatAPI, timer_id: integer)
 ---@field get_userstore_item fun(self: UniChatAPI, key: string): string?
 ---@field set_userstore_item fun(self: UniChatAPI, key: string, value: string | nil)
 ---@field notify fun(self: UniChatAPI, message: string)
+---@field get_setting fun(self: UniChatAPI, key: string): any
+---@field on_setting_change fun(self: UniChatAPI, callback: fun(key: string, value: any, old_value: any))
+
+---@class UniChatEventListenerFilter
+---@field event_types? string[] Event types such as `unichat:donate`, all types when omitted
+---@field platforms? string[] Platforms such as `twitch`, events without a platform are always received
+
+---@class UniChatHttpRouteRequest
+---@field method string
+---@field path string Path below `/plugin/<plugin_name>`, starting with `/`
+---@field headers table<string, string> Header names are lowercase
+---@field query table<string, string>
+---@field body string
+
+---@class UniChatHttpRouteResponse
+---@field status? integer Defaults to `200`
+---@field headers? table<string, string>
+---@field body? string | table Tables are encoded as JSON
 
 ---@class UniChatScraperOptions
 ---@field validate_url fun(url: string): string
@@ -280,6 +306,7 @@ UniChatBadge = UniChatBadge or nil;
 ---@field query_params table<string, string>?
 ---@field content_type string?
 ---@field basic_auth UniChatBasicAuth?
+---@field timeout_ms integer?
 
 ---@class UniChatHttpResponse
 ---@field ok boolean
@@ -299,6 +326,14 @@ UniChatBadge = UniChatBadge or nil;
 ---@field patch fun(self: UniChatHttp, uri: string, body: string | table | nil, args?: UniChatHttpArgs): UniChatHttpResponse
 ---@field delete fun(self: UniChatHttp, uri: string, args?: UniChatHttpArgs): UniChatHttpResponse
 ---@field head fun(self: UniChatHttp, uri: string): UniChatHttpResponse
+---@field get_async fun(self: UniChatHttp, uri: string, args: UniChatHttpArgs?, callback: UniChatHttpCallback)
+---@field post_async fun(self: UniChatHttp, uri: string, body: string | table | nil, args: UniChatHttpArgs?, callback: UniChatHttpCallback)
+---@field put_async fun(self: UniChatHttp, uri: string, body: string | table | nil, args: UniChatHttpArgs?, callback: UniChatHttpCallback)
+---@field patch_async fun(self: UniChatHttp, uri: string, body: string | table | nil, args: UniChatHttpArgs?, callback: UniChatHttpCallback)
+---@field delete_async fun(self: UniChatHttp, uri: string, args: UniChatHttpArgs?, callback: UniChatHttpCallback)
+---@field head_async fun(self: UniChatHttp, uri: string, args: UniChatHttpArgs?, callback: UniChatHttpCallback)
+
+---@alias UniChatHttpCallback fun(response: UniChatHttpResponse?, err: string?)
 -- ===========================================[ End UniChat HTTP Library ]=========================================== --
 
 -- =============================================[ UniChat JSON Library ]============================================= --
@@ -348,6 +383,23 @@ UniChatBadge = UniChatBadge or nil;
 ---@field random_color_by_seed fun(self: UniChatUtils, seed: string): string
 -- ==========================================[ End UniChat Utils Library ]=========================================== --
 
+-- ==========================================[ UniChat WebSocket Library ]=========================================== --
+---@class UniChatWebSocketOptions
+---@field headers table<string, string>?
+---@field on_open fun()?
+---@field on_message fun(message: string)?
+---@field on_close fun(code: integer?, reason: string?)?
+---@field on_error fun(err: string)?
+
+---@class UniChatWebSocketConnection
+---@field is_open boolean
+---@field send fun(self: UniChatWebSocketConnection, data: string | table)
+---@field close fun(self: UniChatWebSocketConnection, code?: integer, reason?: string)
+
+---@class UniChatWebSocket
+---@field connect fun(self: UniChatWebSocket, url: string, opts?: UniChatWebSocketOptions): UniChatWebSocketConnection
+-- ========================================[ End UniChat WebSocket Library ]========================================= --
+
 -- =============================================[ UniChat YAML Library ]============================================= --
 ---@class UniChatYaml
 ---@field encode fun(self: UniChatYaml, data: table): string
@@ -10,6 +10,7 @@
 
 use anyhow::Error;
 use axum::Router;
+use axum::routing::any;
 use axum::routing::get;
 use axum::routing::post;
 use tokio::net::TcpListener;
@@ -34,6 +35,8 @@ pub async fn start() -> Result<(), Error> {
     let app = Router::new()
         .route("/assets/{*path}", get(routes::assets))
         .route("/gallery/{path}", get(routes::gallery))
+        .route("/plugin/{name}/", any(routes::plugin_root))
+        .route("/plugin/{name}/{*path}", any(routes::plugin))
         .route("/proxy/{*path}", get(routes::proxy))
         .route("/rpc", post(routes::rpc))
         .route("/widget/{name}", get(routes::get_widget))
@@ -10,6 +10,7 @@
 
 mod assets;
 mod gallery;
+mod plugin;
 mod proxy;
 mod rpc;
 mod widget;
@@ -17,6 +18,8 @@ mod ws;
 
 pub use assets::assets;
 pub use gallery::gallery;
+pub use plugin::plugin;
+pub use plugin::plugin_root;
 pub use proxy::proxy;
 pub use rpc::rpc;
 pub use widget::get_widget;
@@ -0,0 +1,92 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::collections::HashMap;
+
+use axum::body::Body;
+use axum::extract::Path;
+use axum::extract::Query;
+use axum::extract::Request;
+use axum::http::StatusCode;
+use axum::response::Response;
+
+use crate::plugins::routes::PluginHttpRequest;
+use crate::plugins::routes::PluginRouteError;
+use crate::plugins::routes::handle_request;
+
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+pub async fn plugin(Path((plugin_name, path)): Path<(String, String)>, Query(query): Query<HashMap<String, String>>, req: Request<Body>) -> Response {
+    return dispatch(plugin_name, format!("/{}", path), query, req).await;
+}
+
+/// Serves routes registered as `/`, the wildcard of [`plugin`] does not match an empty path.
+pub async fn plugin_root(Path(plugin_name): Path<String>, Query(query): Query<HashMap<String, String>>, req: Request<Body>) -> Response {
+    return dispatch(plugin_name, String::from("/"), query, req).await;
+}
+
+async fn dispatch(plugin_name: String, path: String, query: HashMap<String, String>, req: Request<Body>) -> Response {
+    let (parts, body) = req.into_parts();
+    let body = match axum::body::to_bytes(body, MAX_BODY_BYTES).await {
+        Ok(body) => body,
+        Err(err) => {
+            return Response::builder().status(StatusCode::PAYLOAD_TOO_LARGE)
+                .body(Body::from(format!("Failed to read request body: {}", err)))
+                .unwrap();
+        }
+    };
+
+    let mut headers: HashMap<String, String> = HashMap::new();
+    for (key, value) in parts.headers.iter() {
+        if let Ok(value) = value.to_str() {
+            headers.entry(key.to_string()).and_modify(|v| *v = format!("{}, {}", v, value)).or_insert(value.to_string());
+        }
+    }
+
+    let request = PluginHttpRequest {
+        method: parts.method.to_string(),
+        path: path,
+        headers: headers,
+        query: query,
+        body: body.to_vec()
+    };
+
+    // Handlers run LUA code, keep them off the async workers.
+    let handled = tauri::async_runtime::spawn_blocking(move || handle_request(&plugin_name, request)).await;
+    match handled {
+        Ok(Ok(response)) => {
+            let mut builder = Response::builder().status(response.status);
+            for (key, value) in response.headers.iter() {
+                builder = builder.header(key, value);
+            }
+
+            return builder.body(Body::from(response.body)).unwrap_or_else(|err| {
+                return Response::builder().status(StatusCode::INTERNAL_SERVER_ERROR)
+                    .body(Body::from(format!("Invalid plugin response: {}", err)))
+                    .unwrap();
+            });
+        }
+        Ok(Err(PluginRouteError::NotFound(message))) => {
+            return Response::builder().status(StatusCode::NOT_FOUND)
+                .body(Body::from(message))
+                .unwrap();
+        }
+        Ok(Err(PluginRouteError::Failed(message))) => {
+            return Response::builder().status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body(Body::from(message))
+                .unwrap();
+        }
+        Err(err) => {
+            return Response::builder().status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body(Body::from(format!("{:#?}", err)))
+                .unwrap();
+        }
+    }
+}
@@ -12,6 +12,7 @@ use axum::body::Body;
 use axum::body::Bytes;
 use axum::response::Response;
 
+use crate::plugins::rpc::call_method;
 use crate::utils::jsonrpc::JsonRPCError;
 use crate::utils::jsonrpc::JsonRPCRequest;
 use crate::utils::jsonrpc::JsonRPCResponse;
@@ -54,7 +55,18 @@ fn process_rpc_request(body: JsonRPCRequest) -> Result<JsonRPCResponse, JsonRPCE
             }
         }
         _ => {
-            return Err(JsonRPCError::method_not_found(id, Some(format!("Method '{}' not found", method))));
+            let Some(ok) = id else {
+                return Err(JsonRPCError::invalid_request(None, Some("Missing id in JSON-RPC request")));
+            };
+
+            match call_method(&method, body.params().unwrap_or_default()) {
+                Ok(result) => {
+                    return Ok(JsonRPCResponse::new(ok, result));
+                }
+                Err(err) => {
+                    return Err(err.into_jsonrpc_error(ok));
+                }
+            }
         }
 
     }
@@ -75,10 +87,15 @@ pub async fn rpc(body: Bytes) -> Response {
         }
     }
 
+    // Plugin methods run LUA code, keep them off the async workers.
+    let id = rpc_request.id();
+    let processed = tauri::async_runtime::spawn_blocking(move || process_rpc_request(rpc_request)).await;
+
     let body_str: String;
-    match process_rpc_request(rpc_request) {
-        Ok(response) => body_str = serde_json::to_string(&response).unwrap(),
-        Err(err) => body_str = serde_json::to_string(&err).unwrap()
+    match processed {
+        Ok(Ok(response)) => body_str = serde_json::to_string(&response).unwrap(),
+        Ok(Err(err)) => body_str = serde_json::to_string(&err).unwrap(),
+        Err(e) => body_str = serde_json::to_string(&JsonRPCError::internal_error(id, Some(format!("{:#?}", e)))).unwrap()
     }
 
     return Response::builder()
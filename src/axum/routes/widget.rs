@@ -15,6 +15,7 @@ use anyhow::anyhow;
 use anyhow::Error;
 use axum::body::Body;
 use axum::extract::Path;
+use axum::extract::Query;
 use axum::extract::Request;
 use axum::http::StatusCode;
 use axum::response::Response;
@@ -24,13 +25,21 @@ use crate::axum::utils::serve_partial_content;
 use crate::utils::constants::BASE_REST_PORT;
 use crate::utils::safe_guard_path;
 use crate::widgets::WidgetMetadata;
+use crate::widgets::fields;
 use crate::widgets::get_widget_from_rest_path;
+use crate::widgets::template;
+use crate::widgets::template::TemplateContext;
 
 static WIDGET_TEMPLATE: &str = include_str!("./../static/index.html.template");
 
-fn load_fieldstate(widget: &WidgetMetadata) -> Result<HashMap<String, serde_json::Value>, Error> {
+#[derive(serde::Deserialize)]
+pub struct WidgetQuery {
+    instance: Option<String>
+}
+
+pub fn load_fieldstate(widget: &WidgetMetadata, instance: Option<&str>) -> Result<HashMap<String, serde_json::Value>, Error> {
     let fields_map = widget.fields();
-    let fieldstate_map = widget.fieldstate();
+    let fieldstate_map = widget.fieldstate(instance);
 
     let mut final_fieldstate: HashMap<String, serde_json::Value> = HashMap::new();
 
@@ -40,11 +49,13 @@ fn load_fieldstate(widget: &WidgetMetadata) -> Result<HashMap<String, serde_json
         }
 
         if let Some(state_value) = fieldstate_map.get(key) {
-            final_fieldstate.insert(key.clone(), state_value.clone());
+            final_fieldstate.insert(key.clone(), fields::serialize_value(value, state_value));
         } else {
             let obj = value.as_object().ok_or(anyhow!("Invalid field definition"))?;
-            if let Some(default_value) = obj.get("value") {
-                final_fieldstate.insert(key.clone(), default_value.clone());
+            let default_value = obj.get("value").cloned().unwrap_or(serde_json::Value::Null);
+            let serialized = fields::serialize_value(value, &default_value);
+            if !serialized.is_null() || obj.contains_key("value") {
+                final_fieldstate.insert(key.clone(), serialized);
             }
         }
     }
@@ -52,6 +63,15 @@ fn load_fieldstate(widget: &WidgetMetadata) -> Result<HashMap<String, serde_json
     return Ok(final_fieldstate);
 }
 
+fn render_template(widget: &WidgetMetadata, source: &str, context: TemplateContext, variables: &HashMap<String, serde_json::Value>) -> String {
+    let (content, warnings) = template::render(source, context, variables);
+    for warning in warnings {
+        log::warn!("Template warning on widget '{}' ({:?}): {}", widget.rest_path, context, warning);
+    }
+
+    return content;
+}
+
 /* ================================================================================================================== */
 
 pub async fn get_widget_assets(Path((widget_name, asset_path)): Path<(String, String)>, req: Request<Body>) -> Response {
@@ -92,7 +112,7 @@ pub async fn get_widget_assets(Path((widget_name, asset_path)): Path<(String, St
 
 /* ================================================================================================================== */
 
-pub async fn get_widget(Path(widget_name): Path<String>, req: Request<Body>) -> Response {
+pub async fn get_widget(Path(widget_name): Path<String>, Query(query): Query<WidgetQuery>, req: Request<Body>) -> Response {
     let widget_metadata: WidgetMetadata;
     match get_widget_from_rest_path(&widget_name) {
         Ok(widget) => widget_metadata = widget,
@@ -103,35 +123,41 @@ pub async fn get_widget(Path(widget_name): Path<String>, req: Request<Body>) ->
         }
     }
 
-    let css = widget_metadata.widget_css();
-    let html = widget_metadata.widget_html();
-    let js = widget_metadata.widget_js();
-
-    let mut content = String::from(WIDGET_TEMPLATE);
-    content = content.replace("{{WIDGET_STYLE}}", &css);
-    content = content.replace("{{WIDGET_HTML}}", &html);
-    content = content.replace("{{WIDGET_SCRIPT}}", &js);
+    let instance = query.instance.as_deref().filter(|i| !i.is_empty() && *i != "default");
+    if let Some(instance) = instance {
+        let instance_exists = widget_metadata.fieldstate_path(Some(instance)).is_ok_and(|p| p.is_file());
+        if !instance_exists {
+            return Response::builder().status(StatusCode::NOT_FOUND)
+                .body(Body::from(format!("Instance '{}' not found for widget '{}'", instance, widget_name)))
+                .unwrap();
+        }
+    }
 
     let uri = req.uri();
     let scheme = uri.scheme_str().unwrap_or("http");
     let host = uri.host().unwrap_or("localhost");
     let base_url = format!("{}://{}:{}/widget/{}/", scheme, host, BASE_REST_PORT, widget_name);
-    content = content.replace("{{WIDGET_BASE_URL}}", &base_url);
-
-    let fieldstate = load_fieldstate(&widget_metadata).unwrap_or_default();
-    for (key, value) in fieldstate.iter() {
-        match serde_plain::to_string(value) {
-            Ok(value_str) => {
-                content = content.replace(&format!("{{{{{}}}}}", key), &value_str);
-                content = content.replace(&format!("{{{}}}", key), &value_str);
-            },
-            Err(err) => {
-                log::error!("Failed to serialize fieldstate value for key '{}': {:#?}", key, err);
-                continue;
-            }
-        }
+
+    let mut ws_query = format!("?widget={}", template::encode_uri_component(&widget_name));
+    if let Some(instance) = instance {
+        ws_query.push_str(&format!("&instance={}", template::encode_uri_component(instance)));
     }
 
+    let mut variables = load_fieldstate(&widget_metadata, instance).unwrap_or_default();
+    variables.insert(String::from(template::BASE_URL_KEY), serde_json::Value::String(base_url.clone()));
+
+    let css = render_template(&widget_metadata, &widget_metadata.widget_css(), TemplateContext::Css, &variables);
+    let html = render_template(&widget_metadata, &widget_metadata.widget_html(), TemplateContext::Html, &variables);
+    let js = render_template(&widget_metadata, &widget_metadata.widget_js(), TemplateContext::Js, &variables);
+
+    let content = template::fill_placeholders(WIDGET_TEMPLATE, &[
+        ("WIDGET_BASE_URL", &base_url),
+        ("WIDGET_WS_QUERY", &ws_query),
+        ("WIDGET_STYLE", &css),
+        ("WIDGET_HTML", &html),
+        ("WIDGET_SCRIPT", &js)
+    ]);
+
     return Response::builder().status(StatusCode::OK)
         .header("Content-Type", "text/html; charset=utf-8")
         .body(Body::from(content))
@@ -8,6 +8,7 @@
  * SPDX-License-Identifier: EPL-2.0
  ******************************************************************************/
 
+use axum::extract::Query;
 use axum::extract::WebSocketUpgrade;
 use axum::extract::ws::Message;
 use axum::extract::ws::WebSocket;
@@ -16,10 +17,41 @@ use futures::SinkExt as _;
 use futures::StreamExt as _;
 use tokio::sync::broadcast::error::RecvError;
 
+use crate::axum::routes::widget::load_fieldstate;
 use crate::events;
+use crate::events::unichat::UniChatEvent;
 use crate::utils::userstore;
+use crate::widgets::filter::WidgetEventFilter;
+use crate::widgets::get_widget_from_rest_path;
 
-async fn handle_socket(socket: WebSocket) {
+#[derive(serde::Deserialize)]
+pub struct WsQuery {
+    widget: Option<String>,
+    instance: Option<String>
+}
+
+/// Loads the event filter configured on the widget fields, connections without a widget receive every event.
+fn load_filter(query: &WsQuery) -> Option<WidgetEventFilter> {
+    let widget_name = query.widget.as_deref().filter(|w| !w.is_empty())?;
+    let widget = match get_widget_from_rest_path(widget_name) {
+        Ok(widget) => widget,
+        Err(err) => {
+            log::warn!("Unable to load event filter for widget '{}': {:#?}", widget_name, err);
+            return None;
+        }
+    };
+
+    let instance = query.instance.as_deref().filter(|i| !i.is_empty() && *i != "default");
+    let variables = load_fieldstate(&widget, instance).unwrap_or_default();
+
+    return WidgetEventFilter::from_variables(&variables);
+}
+
+fn is_allowed(filter: &Option<WidgetEventFilter>, event: &UniChatEvent) -> bool {
+    return filter.as_ref().is_none_or(|f| f.matches(event));
+}
+
+async fn handle_socket(socket: WebSocket, filter: Option<WidgetEventFilter>) {
     let (mut sender, mut receiver) = socket.split();
 
     /* ====================================================================== */
@@ -36,7 +68,7 @@ async fn handle_socket(socket: WebSocket) {
 
     /* ====================================================================== */
 
-    let history_data = events::latest_events();
+    let history_data: Vec<UniChatEvent> = events::latest_events().into_iter().filter(|e| is_allowed(&filter, e)).collect();
     let history_event = serde_json::json!({ "type": "unichat:history", "data": history_data });
     if let Ok(parsed) = serde_json::to_string(&history_event) {
         if let Err(err) = sender.send(Message::Text(parsed.into())).await {
@@ -53,6 +85,7 @@ async fn handle_socket(socket: WebSocket) {
         tokio::select! {
             event = rx.recv() => {
                 match event {
+                    Ok(event) if !is_allowed(&filter, &event) => {},
                     Ok(event) => {
                         match serde_json::to_string(&event) {
                             Ok(msg) => {
@@ -92,6 +125,7 @@ async fn handle_socket(socket: WebSocket) {
     }
 }
 
-pub async fn ws(ws: WebSocketUpgrade) -> impl IntoResponse {
-    return ws.on_upgrade(handle_socket);
+pub async fn ws(ws: WebSocketUpgrade, Query(query): Query<WsQuery>) -> impl IntoResponse {
+    let filter = load_filter(&query);
+    return ws.on_upgrade(move |socket| handle_socket(socket, filter));
 }
@@ -0,0 +1,155 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::anyhow;
+use anyhow::Error;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::UNICHAT_VERSION;
+use crate::utils::semver;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CatalogItemKind {
+    Widget,
+    Plugin
+}
+
+/// An entry of the catalog index, `download` may be relative to the index URL.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogEntry {
+    pub name: String,
+    pub description: Option<String>,
+    pub version: String,
+    pub author: Option<String>,
+    pub homepage: Option<String>,
+    pub unichat: Option<String>,
+    pub download: String
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CatalogIndex {
+    #[serde(default)]
+    pub widgets: Vec<CatalogEntry>,
+    #[serde(default)]
+    pub plugins: Vec<CatalogEntry>
+}
+
+/// An installed widget or plugin, `from_catalog` is set when the catalog owns its folder.
+#[derive(Clone, Debug)]
+pub struct CatalogInstall {
+    pub version: String,
+    pub from_catalog: bool
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogItem {
+    pub kind: CatalogItemKind,
+    #[serde(flatten)]
+    pub entry: CatalogEntry,
+    pub installed_version: Option<String>,
+    pub installed_from_catalog: bool,
+    pub has_update: bool,
+    pub compatible: bool
+}
+
+impl CatalogIndex {
+    pub fn find(&self, kind: CatalogItemKind, name: &str) -> Option<&CatalogEntry> {
+        let entries = match kind {
+            CatalogItemKind::Widget => &self.widgets,
+            CatalogItemKind::Plugin => &self.plugins
+        };
+
+        return entries.iter().find(|e| e.name == name);
+    }
+
+    /// Lists the valid entries next to the installed versions, entries with an invalid version are skipped.
+    /// Only installs owned by the catalog report updates, others just share the entry name.
+    pub fn items(&self, installed: &HashMap<(CatalogItemKind, String), CatalogInstall>) -> Vec<CatalogItem> {
+        let mut items = Vec::new();
+        let entries = self.widgets.iter().map(|e| (CatalogItemKind::Widget, e)).chain(self.plugins.iter().map(|e| (CatalogItemKind::Plugin, e)));
+
+        for (kind, entry) in entries {
+            if let Err(err) = semver::Version::parse(&entry.version) {
+                log::warn!("Skipping catalog entry '{}' with invalid version '{}': {:?}", entry.name, entry.version, err);
+                continue;
+            }
+
+            let install = installed.get(&(kind, entry.name.clone()));
+            let installed_from_catalog = install.is_some_and(|i| i.from_catalog);
+            let has_update = install.is_some_and(|i| i.from_catalog && semver::gt(&entry.version, &i.version));
+
+            items.push(CatalogItem {
+                kind: kind,
+                entry: entry.clone(),
+                installed_version: install.map(|i| i.version.clone()),
+                installed_from_catalog: installed_from_catalog,
+                has_update: has_update,
+                compatible: is_compatible(entry)
+            });
+        }
+
+        return items;
+    }
+}
+
+/// Entries without an `unichat` range (or with an invalid one) are considered compatible.
+pub fn is_compatible(entry: &CatalogEntry) -> bool {
+    let Some(unichat) = &entry.unichat else {
+        return true;
+    };
+
+    return match (semver::VersionRange::parse(unichat), semver::Version::parse(UNICHAT_VERSION)) {
+        (Ok(range), Ok(version)) => range.matches(&version),
+        _ => true
+    };
+}
+
+pub fn is_remote_location(location: &str) -> bool {
+    return location.starts_with("http://") || location.starts_with("https://");
+}
+
+/// Resolves a `download` location against the index location, which can be an URL or a local file.
+/// Remote indexes may only point to http(s) downloads, local files are reserved to local indexes.
+pub fn resolve_location(index_location: &str, download: &str) -> Result<String, Error> {
+    if download.contains("://") {
+        if is_remote_location(index_location) && !is_remote_location(download) {
+            return Err(anyhow!("Download location '{}' of a remote catalog must be an http(s) URL", download));
+        }
+
+        return Ok(String::from(download));
+    }
+
+    if let Some(path) = index_location.strip_prefix("file://") {
+        let parent = Path::new(path).parent().unwrap_or(Path::new(""));
+        return Ok(format!("file://{}", parent.join(download).to_string_lossy()));
+    } else if !index_location.contains("://") {
+        let parent = Path::new(index_location).parent().unwrap_or(Path::new(""));
+        return Ok(parent.join(download).to_string_lossy().to_string());
+    } else if !is_remote_location(index_location) {
+        return Err(anyhow!("Unsupported catalog location '{}'", index_location));
+    }
+
+    let (scheme, rest) = index_location.split_once("://").unwrap_or_default();
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+    if let Some(path) = download.strip_prefix('/') {
+        let host = rest.split('/').next().unwrap_or_default();
+        return Ok(format!("{}://{}/{}", scheme, host, path));
+    }
+
+    let base = rest.rsplit_once('/').map(|(base, _)| base).unwrap_or(rest);
+    return Ok(format!("{}://{}/{}", scheme, base, download));
+}
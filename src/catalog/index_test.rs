@@ -0,0 +1,85 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::collections::HashMap;
+
+use serde_json::json;
+
+use crate::catalog::index::resolve_location;
+use crate::catalog::index::CatalogIndex;
+use crate::catalog::index::CatalogInstall;
+use crate::catalog::index::CatalogItemKind;
+
+fn index() -> CatalogIndex {
+    return serde_json::from_value(json!({
+        "widgets": [
+            { "name": "alerts", "version": "1.2.0", "download": "widgets/alerts.ucwidget" },
+            { "name": "broken", "version": "latest", "download": "widgets/broken.ucwidget" },
+            { "name": "future", "version": "1.0.0", "unichat": "[99.0.0,)", "download": "widgets/future.ucwidget" }
+        ],
+        "plugins": [
+            { "name": "alerts", "version": "2.0.0", "download": "https://cdn.example.com/alerts.ucplugin" }
+        ]
+    })).unwrap();
+}
+
+#[test]
+fn test_items_detect_updates() {
+    let mut installed = HashMap::new();
+    installed.insert((CatalogItemKind::Widget, String::from("alerts")), CatalogInstall { version: String::from("1.1.0"), from_catalog: true });
+    installed.insert((CatalogItemKind::Plugin, String::from("alerts")), CatalogInstall { version: String::from("2.0.0"), from_catalog: true });
+    installed.insert((CatalogItemKind::Widget, String::from("future")), CatalogInstall { version: String::from("0.0.0"), from_catalog: false });
+
+    let items = index().items(&installed);
+    assert_eq!(items.len(), 3);
+
+    assert_eq!(items[0].kind, CatalogItemKind::Widget);
+    assert_eq!(items[0].installed_version.as_deref(), Some("1.1.0"));
+    assert!(items[0].has_update);
+    assert!(items[0].compatible);
+
+    assert_eq!(items[1].entry.name, "future");
+    assert_eq!(items[1].installed_version.as_deref(), Some("0.0.0"));
+    assert!(!items[1].installed_from_catalog);
+    assert!(!items[1].has_update);
+    assert!(!items[1].compatible);
+
+    assert_eq!(items[2].kind, CatalogItemKind::Plugin);
+    assert!(!items[2].has_update);
+}
+
+#[test]
+fn test_find() {
+    let index = index();
+    assert_eq!(index.find(CatalogItemKind::Plugin, "alerts").unwrap().version, "2.0.0");
+    assert!(index.find(CatalogItemKind::Plugin, "future").is_none());
+}
+
+#[test]
+fn test_resolve_location() {
+    assert_eq!(resolve_location("https://example.com/api/catalog.json?v=1", "widgets/a.ucwidget").unwrap(), "https://example.com/api/widgets/a.ucwidget");
+    assert_eq!(resolve_location("https://example.com/api/catalog.json", "/files/a.ucwidget").unwrap(), "https://example.com/files/a.ucwidget");
+    assert_eq!(resolve_location("https://example.com", "a.ucwidget").unwrap(), "https://example.com/a.ucwidget");
+    assert_eq!(resolve_location("https://example.com/catalog.json", "http://other.com/a.ucwidget").unwrap(), "http://other.com/a.ucwidget");
+    assert_eq!(resolve_location("file:///tmp/catalog/index.json", "a.ucwidget").unwrap(), "file:///tmp/catalog/a.ucwidget");
+    assert_eq!(resolve_location("/tmp/catalog/index.json", "widgets/a.ucwidget").unwrap(), "/tmp/catalog/widgets/a.ucwidget");
+}
+
+#[test]
+fn test_resolve_location_keeps_remote_catalogs_remote() {
+    assert!(resolve_location("https://example.com/catalog.json", "file:///etc/passwd").is_err());
+    assert!(resolve_location("http://example.com/catalog.json", "ftp://example.com/a.ucwidget").is_err());
+    assert_eq!(resolve_location("https://example.com/catalog.json", "/etc/passwd").unwrap(), "https://example.com/etc/passwd");
+    assert_eq!(resolve_location("https://example.com/catalog.json", "../a.ucwidget").unwrap(), "https://example.com/../a.ucwidget");
+
+    // Local catalogs may still point to local files and remote downloads.
+    assert_eq!(resolve_location("file:///tmp/catalog/index.json", "file:///opt/a.ucwidget").unwrap(), "file:///opt/a.ucwidget");
+    assert_eq!(resolve_location("/tmp/catalog/index.json", "https://example.com/a.ucwidget").unwrap(), "https://example.com/a.ucwidget");
+}
@@ -0,0 +1,204 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use anyhow::Error;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::catalog::index::CatalogIndex;
+use crate::catalog::index::CatalogInstall;
+use crate::catalog::index::CatalogItem;
+use crate::catalog::index::CatalogItemKind;
+use crate::plugins;
+use crate::plugins::archive::PluginArchive;
+use crate::utils::properties;
+use crate::utils::properties::AppPaths;
+use crate::utils::settings;
+use crate::utils::settings::SETTINGS_CATALOG_URL_KEY;
+use crate::utils::ureq;
+use crate::widgets;
+use crate::widgets::WidgetSource;
+use crate::widgets::archive::WidgetArchive;
+
+#[cfg(test)] mod index_test;
+pub mod index;
+
+/// Written into every folder installed by the catalog, folders without it are never replaced silently.
+pub const CATALOG_MARKER_FILE: &str = ".catalog.json";
+const CATALOG_CACHE_TTL: Duration = Duration::from_secs(3600);
+const MAX_DOWNLOAD_SIZE: u64 = 64 * 1024 * 1024;
+
+#[derive(Serialize, Deserialize)]
+struct CachedCatalog {
+    url: String,
+    index: CatalogIndex
+}
+
+#[derive(Serialize, Deserialize)]
+struct CatalogMarker {
+    url: String,
+    version: String
+}
+
+/// The catalog location from settings, there is no default catalog.
+pub fn catalog_url() -> Result<String, Error> {
+    let url: String = settings::get_item(SETTINGS_CATALOG_URL_KEY).unwrap_or_default();
+    if url.trim().is_empty() {
+        return Err(anyhow!("No catalog configured, set a catalog URL in Settings > General"));
+    }
+
+    return Ok(String::from(url.trim()));
+}
+
+fn is_from_catalog(path: &Path) -> bool {
+    return path.join(CATALOG_MARKER_FILE).is_file();
+}
+
+/// Reads an URL or a local file (plain path or `file://`), limited to `MAX_DOWNLOAD_SIZE`.
+fn read_location(location: &str) -> Result<Vec<u8>, Error> {
+    if index::is_remote_location(location) {
+        let mut response = ureq::get(location).call()?;
+        let data = response.body_mut().with_config().limit(MAX_DOWNLOAD_SIZE).read_to_vec()?;
+        return Ok(data);
+    }
+
+    let path = PathBuf::from(location.strip_prefix("file://").unwrap_or(location));
+    if fs::metadata(&path)?.len() > MAX_DOWNLOAD_SIZE {
+        return Err(anyhow!("File '{:?}' is larger than {} MiB", path, MAX_DOWNLOAD_SIZE / 1024 / 1024));
+    }
+
+    return Ok(fs::read(&path)?);
+}
+
+/// Loads the catalog index, remote indexes are cached for an hour unless `force` is set.
+pub fn fetch_index(force: bool) -> Result<(String, CatalogIndex), Error> {
+    let url = catalog_url()?;
+    if !index::is_remote_location(&url) {
+        let index = serde_json::from_slice(&read_location(&url)?)?;
+        return Ok((url, index));
+    }
+
+    let app_cache_dir = properties::get_app_path(AppPaths::AppCache);
+    if !app_cache_dir.exists() {
+        fs::create_dir_all(&app_cache_dir)?;
+    }
+
+    let cached_catalog_path = app_cache_dir.join("cached_catalog.json");
+    if let Ok(metadata) = fs::metadata(&cached_catalog_path) {
+        let duration = metadata.modified()?.elapsed()?;
+        if !force && duration < CATALOG_CACHE_TTL {
+            let data = fs::read_to_string(&cached_catalog_path)?;
+            match serde_json::from_str::<CachedCatalog>(&data) {
+                Ok(cached) if cached.url == url => {
+                    log::info!("Using cached catalog file (age: {} seconds)", duration.as_secs());
+                    return Ok((url, cached.index));
+                }
+                Ok(_) => log::info!("Cached catalog belongs to another URL, fetching '{}'", url),
+                Err(err) => log::warn!("Failed to parse cached catalog file: {:?}", err)
+            }
+        }
+    }
+
+    log::info!("Fetching catalog from '{}'...", url);
+    let index: CatalogIndex = serde_json::from_slice(&read_location(&url)?)?;
+    let cached = CachedCatalog { url: url.clone(), index: index };
+    fs::write(&cached_catalog_path, serde_json::to_string(&cached)?)?;
+
+    return Ok((url, cached.index));
+}
+
+/// Versions of the installed user widgets (from `widget.yaml`) and of every loaded plugin.
+fn installed_versions() -> Result<HashMap<(CatalogItemKind, String), CatalogInstall>, Error> {
+    let mut installed = HashMap::new();
+
+    for widget in widgets::get_widgets()? {
+        if widget.widget_source != WidgetSource::User {
+            continue;
+        }
+
+        let version = widget.manifest.as_ref().and_then(|m| m.version.clone()).unwrap_or(String::from("0.0.0"));
+        let install = CatalogInstall { version: version, from_catalog: is_from_catalog(widget.path()) };
+        installed.insert((CatalogItemKind::Widget, widget.rest_path.clone()), install);
+    }
+
+    for plugin in plugins::get_plugins()? {
+        let install = CatalogInstall { version: plugin.version.to_string(), from_catalog: is_from_catalog(&plugin.get_plugin_path()) };
+        installed.insert((CatalogItemKind::Plugin, plugin.name.clone()), install);
+    }
+
+    return Ok(installed);
+}
+
+pub fn list(force: bool) -> Result<Vec<CatalogItem>, Error> {
+    let (_url, index) = fetch_index(force)?;
+    return Ok(index.items(&installed_versions()?));
+}
+
+/// Downloads and installs a catalog entry into the user folders, replacing an older installed version.
+/// A folder with the same name that the catalog did not install is only replaced when `replace` is set.
+pub fn install(kind: CatalogItemKind, name: &str, replace: bool) -> Result<CatalogItem, Error> {
+    let (url, index) = fetch_index(false)?;
+    let entry = index.find(kind, name).ok_or(anyhow!("Catalog has no {:?} named '{}'", kind, name))?;
+    if !index::is_compatible(entry) {
+        return Err(anyhow!("'{}' v{} is not compatible with this version", entry.name, entry.version));
+    }
+
+    let target_path = match kind {
+        CatalogItemKind::Widget => properties::get_app_path(AppPaths::UniChatUserWidgets).join(&entry.name),
+        CatalogItemKind::Plugin => properties::get_app_path(AppPaths::UniChatUserPlugins).join(&entry.name)
+    };
+
+    if kind == CatalogItemKind::Widget && widgets::is_reserved_widget_name(&entry.name) {
+        return Err(anyhow!("Widget name '{}' is already used by a system or plugin widget", entry.name));
+    } else if target_path.exists() && !is_from_catalog(&target_path) && !replace {
+        return Err(anyhow!("{:?} '{}' was not installed from the catalog, confirm to replace it", kind, entry.name));
+    }
+
+    let download = index::resolve_location(&url, &entry.download)?;
+    log::info!("Installing {:?} '{}' v{} from '{}'", kind, entry.name, entry.version, download);
+    let data = read_location(&download)?;
+
+    let marker = serde_json::to_string_pretty(&CatalogMarker { url: url.clone(), version: entry.version.clone() })?;
+    match kind {
+        CatalogItemKind::Widget => {
+            let archive = WidgetArchive::from_slice(&data)?;
+            let user_widgets_path = properties::get_app_path(AppPaths::UniChatUserWidgets);
+            let widget_path = archive.install(&user_widgets_path, &entry.name, true)?;
+            fs::write(widget_path.join(CATALOG_MARKER_FILE), marker)?;
+            widgets::reload_user_widgets()?;
+        }
+        CatalogItemKind::Plugin => {
+            let archive = PluginArchive::from_slice(&data)?;
+            if archive.name != entry.name {
+                return Err(anyhow!("Downloaded plugin '{}' does not match catalog entry '{}'", archive.name, entry.name));
+            }
+
+            let user_plugins_path = properties::get_app_path(AppPaths::UniChatUserPlugins);
+            let plugin_path = archive.install(&user_plugins_path, true)?;
+            fs::write(plugin_path.join(CATALOG_MARKER_FILE), marker)?;
+            plugins::reload_plugin(&entry.name)?;
+        }
+    }
+
+    let items = index.items(&installed_versions()?);
+    let mut item = items.into_iter().find(|i| i.kind == kind && i.entry.name == name).ok_or(anyhow!("Catalog entry '{}' disappeared", name))?;
+    item.installed_version = Some(item.entry.version.clone());
+    item.installed_from_catalog = true;
+    item.has_update = false;
+
+    return Ok(item);
+}
@@ -0,0 +1,28 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use tauri::AppHandle;
+use tauri::Runtime;
+
+use crate::catalog;
+use crate::catalog::index::CatalogItem;
+use crate::catalog::index::CatalogItemKind;
+
+#[tauri::command]
+pub async fn get_catalog<R: Runtime>(_app: AppHandle<R>, force: Option<bool>) -> Result<Vec<CatalogItem>, String> {
+    let items = catalog::list(force.unwrap_or(false)).map_err(|e| format!("Failed to load catalog: {:#?}", e))?;
+    return Ok(items);
+}
+
+#[tauri::command]
+pub async fn install_catalog_item<R: Runtime>(_app: AppHandle<R>, kind: CatalogItemKind, name: String, replace: Option<bool>) -> Result<CatalogItem, String> {
+    let item = catalog::install(kind, &name, replace.unwrap_or(false)).map_err(|e| format!("Failed to install '{}' from catalog: {:#?}", name, e))?;
+    return Ok(item);
+}
@@ -29,6 +29,7 @@ use crate::utils::properties::AppPaths;
 use crate::utils::semver;
 use crate::utils::ureq;
 
+pub mod catalog;
 pub mod currency;
 pub mod emulator;
 pub mod gallery;
@@ -10,11 +10,15 @@
 
 use std::path::PathBuf;
 
+use indexmap::IndexMap;
+use serde_json::Map;
+use serde_json::Value;
 use tauri::AppHandle;
 use tauri::Runtime;
 
 use crate::plugins;
 use crate::plugins::PluginStatus;
+use crate::plugins::UniChatPlugin;
 use crate::utils::base64;
 use crate::utils::properties;
 use crate::utils::properties::AppPaths;
@@ -29,6 +33,10 @@ pub struct SerializedPluginMetadata {
     pub license: Option<String>,
     pub homepage: Option<String>,
     pub dependencies: Vec<String>,
+    pub permissions: Vec<String>,
+    pub pending_permissions: Vec<String>,
+    pub has_settings: bool,
+    pub limit_failures: u32,
 
     pub icon: Option<String>,
     pub status: PluginStatus,
@@ -36,34 +44,82 @@ pub struct SerializedPluginMetadata {
     pub plugin_path: Option<PathBuf>
 }
 
+fn serialize_plugin(plugin: &UniChatPlugin) -> SerializedPluginMetadata {
+    let mut plugin_path = Some(plugin.get_plugin_path());
+    if plugin.get_plugin_path().starts_with(properties::get_app_path(AppPaths::UniChatSystemPlugins)) {
+        plugin_path = None;
+    }
+
+    return SerializedPluginMetadata {
+        name: plugin.name.clone(),
+        description: plugin.description.clone(),
+        version: plugin.version.to_string(),
+        author: plugin.author.clone(),
+        license: plugin.license.clone(),
+        homepage: plugin.homepage.clone(),
+        dependencies: plugin.dependencies.iter().map(|(name, version_range)| format!("{}@{}", name, version_range)).collect(),
+        permissions: plugin.permissions.to_strings(),
+        pending_permissions: plugins::get_pending_permissions(plugin),
+        has_settings: !plugin.setting_fields.is_empty(),
+        limit_failures: plugin.get_limit_failures(),
+
+        icon: plugin.get_icon().map(|bytes| format!("data:image/png;base64,{}", base64::encode(bytes))),
+        status: plugin.get_status(),
+        messages: plugin.get_messages(),
+        plugin_path: plugin_path,
+    };
+}
+
 #[tauri::command]
 pub async fn get_plugins<R: Runtime>(_app: AppHandle<R>) -> Result<Vec<SerializedPluginMetadata>, String> {
     let mut serialized_plugins: Vec<SerializedPluginMetadata> = Vec::new();
     let plugins = plugins::get_plugins().map_err(|e| format!("An error occurred on get plugins: {:#?}", e))?;
 
     for plugin in plugins {
-        let mut plugin_path = Some(plugin.get_plugin_path());
-        if plugin.get_plugin_path().starts_with(properties::get_app_path(AppPaths::UniChatSystemPlugins)) {
-            plugin_path = None;
-        }
-
-        let metadata = SerializedPluginMetadata {
-            name: plugin.name.clone(),
-            description: plugin.description.clone(),
-            version: plugin.version.to_string(),
-            author: plugin.author.clone(),
-            license: plugin.license.clone(),
-            homepage: plugin.homepage.clone(),
-            dependencies: plugin.dependencies.iter().map(|(name, version_range)| format!("{}@{}", name, version_range)).collect(),
-
-            icon: plugin.get_icon().map(|bytes| format!("data:image/png;base64,{}", base64::encode(bytes))),
-            status: plugin.get_status(),
-            messages: plugin.get_messages(),
-            plugin_path: plugin_path,
-        };
-
-        serialized_plugins.push(metadata);
+        serialized_plugins.push(serialize_plugin(&plugin));
     }
 
     return Ok(serialized_plugins);
 }
+
+#[tauri::command]
+pub async fn reload_plugin<R: Runtime>(_app: AppHandle<R>, plugin_name: String) -> Result<SerializedPluginMetadata, String> {
+    let plugin = plugins::reload_plugin(&plugin_name).map_err(|e| format!("An error occurred on reload plugin: {:#?}", e))?;
+    return Ok(serialize_plugin(&plugin));
+}
+
+#[tauri::command]
+pub async fn unload_plugin<R: Runtime>(_app: AppHandle<R>, plugin_name: String) -> Result<(), String> {
+    plugins::unload_plugin(&plugin_name).map_err(|e| format!("An error occurred on unload plugin: {:#?}", e))?;
+    return Ok(());
+}
+
+#[tauri::command]
+pub async fn toggle_plugin_state<R: Runtime>(_app: AppHandle<R>, plugin_name: String, new_state: bool) -> Result<SerializedPluginMetadata, String> {
+    let plugin = plugins::set_plugin_enabled(&plugin_name, new_state).map_err(|e| format!("An error occurred on toggle plugin state: {:#?}", e))?;
+    return Ok(serialize_plugin(&plugin));
+}
+
+#[tauri::command]
+pub async fn acknowledge_plugin_permissions<R: Runtime>(_app: AppHandle<R>, plugin_name: String) -> Result<(), String> {
+    plugins::acknowledge_permissions(&plugin_name).map_err(|e| format!("An error occurred on acknowledge plugin permissions: {:#?}", e))?;
+    return Ok(());
+}
+
+#[tauri::command]
+pub async fn get_plugin_setting_fields<R: Runtime>(_app: AppHandle<R>, plugin_name: String) -> Result<IndexMap<String, Value>, String> {
+    let plugin = plugins::get_plugin(&plugin_name).map_err(|e| format!("An error occurred on get plugin setting fields: {:#?}", e))?;
+    return Ok(plugin.setting_fields.clone());
+}
+
+#[tauri::command]
+pub async fn get_plugin_settings<R: Runtime>(_app: AppHandle<R>, plugin_name: String) -> Result<Map<String, Value>, String> {
+    let settings = plugins::get_plugin_settings(&plugin_name).map_err(|e| format!("An error occurred on get plugin settings: {:#?}", e))?;
+    return Ok(settings);
+}
+
+#[tauri::command]
+pub async fn set_plugin_settings<R: Runtime>(_app: AppHandle<R>, plugin_name: String, settings: Map<String, Value>) -> Result<Map<String, Value>, String> {
+    let settings = plugins::set_plugin_settings(&plugin_name, &settings).map_err(|e| format!("An error occurred on set plugin settings: {:#?}", e))?;
+    return Ok(settings);
+}
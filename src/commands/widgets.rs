@@ -10,18 +10,62 @@
 
 use std::collections::HashMap;
 use std::fs;
+use std::path::PathBuf;
 
 use indexmap::IndexMap;
+use serde_json::Map;
 use serde_json::Value;
 use tauri::AppHandle;
 use tauri::Runtime;
 
+use crate::utils::get_current_timestamp;
+use crate::utils::properties;
+use crate::utils::properties::AppPaths;
 use crate::widgets;
 use crate::widgets::WidgetMetadata;
 use crate::widgets::WidgetSource;
+use crate::widgets::archive::ARCHIVE_EXTENSION;
+use crate::widgets::archive::WidgetArchive;
+use crate::widgets::archive::WidgetArchivePreview;
+use crate::widgets::fork;
+use crate::widgets::fork::WidgetForkChange;
+use crate::widgets::fork::WidgetForkRecord;
 use crate::widgets::get_widget_from_rest_path;
+use crate::widgets::is_reserved_widget_name;
+use crate::widgets::manifest;
+use crate::widgets::preset;
+use crate::widgets::preset::PRESET_EXTENSION;
+use crate::widgets::preset::WidgetPreset;
+use crate::widgets::preset::WidgetPresetImport;
 use crate::widgets::reload_user_widgets;
 
+fn pack_widget(widget: &WidgetMetadata, include_fieldstate: bool) -> Result<WidgetArchive, String> {
+    let mut archive = WidgetArchive::from_widget_path(widget.path(), include_fieldstate).map_err(|e| format!("Failed to pack widget '{}': {:#?}", widget.rest_path, e))?;
+
+    let manifest = manifest::exported_manifest(widget.path()).map_err(|e| format!("Failed to serialize widget manifest: {:#?}", e))?;
+    if let Some(manifest) = manifest {
+        archive.set_file(manifest::MANIFEST_FILE, manifest.as_bytes());
+    }
+
+    return Ok(archive);
+}
+
+/// `default` (or an empty name) refers to the widget main fieldstate.
+fn normalize_instance(instance: Option<String>) -> Option<String> {
+    return instance.filter(|i| !i.is_empty() && i != "default");
+}
+
+fn write_fieldstate(widget: &WidgetMetadata, instance: Option<&str>, fieldstate: &Map<String, Value>) -> Result<(), String> {
+    let fieldstate_path = widget.fieldstate_path(instance).map_err(|e| format!("{:#?}", e))?;
+    if instance.is_some() && !fieldstate_path.is_file() {
+        return Err(format!("Instance '{}' does not exist", instance.unwrap_or_default()));
+    }
+
+    let data = serde_json::to_string_pretty(fieldstate).map_err(|e| format!("Failed to serialize fieldstate: {:#?}", e))?;
+    fs::write(&fieldstate_path, data).map_err(|e| format!("Failed to write widget '{:?}' fieldstate file: {:#?}", fieldstate_path, e))?;
+    return Ok(());
+}
+
 #[tauri::command]
 pub async fn get_widget_fields<R: Runtime>(_app: tauri::AppHandle<R>, widget: String) -> Result<IndexMap<String, Value>, String> {
     let widget = get_widget_from_rest_path(&widget).map_err(|e| format!("Failed to locate widget '{}': {:#?}", widget, e))?;
@@ -35,10 +79,11 @@ pub async fn get_widget_fields<R: Runtime>(_app: tauri::AppHandle<R>, widget: St
 }
 
 #[tauri::command]
-pub async fn get_widget_fieldstate<R: Runtime>(_app: tauri::AppHandle<R>, widget: String) -> Result<HashMap<String, Value>, String> {
+pub async fn get_widget_fieldstate<R: Runtime>(_app: tauri::AppHandle<R>, widget: String, instance: Option<String>) -> Result<HashMap<String, Value>, String> {
     let widget = get_widget_from_rest_path(&widget).map_err(|e| format!("Failed to locate widget '{}': {:#?}", widget, e))?;
+    let instance = normalize_instance(instance);
     if matches!(widget.widget_source, WidgetSource::User | WidgetSource::UserPlugin(_)) {
-        let fieldstate = widget.fieldstate();
+        let fieldstate = widget.fieldstate(instance.as_deref());
         return Ok(fieldstate);
     }
 
@@ -46,12 +91,15 @@ pub async fn get_widget_fieldstate<R: Runtime>(_app: tauri::AppHandle<R>, widget
 }
 
 #[tauri::command]
-pub async fn set_widget_fieldstate<R: Runtime>(_app: tauri::AppHandle<R>, widget: String, data: String) -> Result<(), String> {
+pub async fn set_widget_fieldstate<R: Runtime>(_app: tauri::AppHandle<R>, widget: String, instance: Option<String>, data: String) -> Result<(), String> {
     let widget = get_widget_from_rest_path(&widget).map_err(|e| format!("Failed to locate widget '{}': {:#?}", widget, e))?;
+    let instance = normalize_instance(instance);
     if matches!(widget.widget_source, WidgetSource::User | WidgetSource::UserPlugin(_)) {
-        let fieldstate_path = widget.fieldstate_path();
+        let fieldstate_path = widget.fieldstate_path(instance.as_deref()).map_err(|e| format!("{:#?}", e))?;
         if fieldstate_path.exists() && !fieldstate_path.is_file() {
             return Err(format!("Widget '{:?}' exists but is not a file", fieldstate_path));
+        } else if instance.is_some() && !fieldstate_path.exists() {
+            return Err(format!("Instance '{}' does not exist", instance.unwrap_or_default()));
         }
 
         fs::write(&fieldstate_path, data).map_err(|e| format!("Failed to write widget '{:?}' fieldstate file: {:#?}", fieldstate_path, e))?;
@@ -61,6 +109,209 @@ pub async fn set_widget_fieldstate<R: Runtime>(_app: tauri::AppHandle<R>, widget
     return Err("Cannot set fieldstate of system or plugin widgets".into());
 }
 
+#[tauri::command]
+pub async fn reset_widget_fieldstate<R: Runtime>(_app: tauri::AppHandle<R>, widget: String, instance: Option<String>, fields: Option<Vec<String>>) -> Result<Vec<String>, String> {
+    let widget = get_widget_from_rest_path(&widget).map_err(|e| format!("Failed to locate widget '{}': {:#?}", widget, e))?;
+    let instance = normalize_instance(instance);
+    if matches!(widget.widget_source, WidgetSource::User | WidgetSource::UserPlugin(_)) {
+        let mut fieldstate: Map<String, Value> = widget.fieldstate(instance.as_deref()).into_iter().collect();
+        let removed = preset::reset(&mut fieldstate, fields.as_deref());
+        write_fieldstate(&widget, instance.as_deref(), &fieldstate)?;
+
+        return Ok(removed);
+    }
+
+    return Err("Cannot reset fieldstate of system or plugin widgets".into());
+}
+
+#[tauri::command]
+pub async fn export_widget_preset<R: Runtime>(_app: tauri::AppHandle<R>, widget: String, instance: Option<String>, path: String) -> Result<(), String> {
+    let widget = get_widget_from_rest_path(&widget).map_err(|e| format!("Failed to locate widget '{}': {:#?}", widget, e))?;
+    let instance = normalize_instance(instance);
+    if matches!(widget.widget_source, WidgetSource::User | WidgetSource::UserPlugin(_)) {
+        let fieldstate: Map<String, Value> = widget.fieldstate(instance.as_deref()).into_iter().collect();
+        let preset = WidgetPreset::from_fieldstate(&widget.rest_path, &widget.fields(), &fieldstate);
+
+        let mut preset_path = PathBuf::from(path);
+        if preset_path.extension().is_none() {
+            preset_path.set_extension(PRESET_EXTENSION);
+        }
+
+        preset.write(&preset_path).map_err(|e| format!("Failed to write widget preset '{:?}': {:#?}", preset_path, e))?;
+        return Ok(());
+    }
+
+    return Err("Cannot export fieldstate of system or plugin widgets".into());
+}
+
+#[tauri::command]
+pub async fn import_widget_preset<R: Runtime>(_app: tauri::AppHandle<R>, widget: String, instance: Option<String>, path: String) -> Result<WidgetPresetImport, String> {
+    let widget = get_widget_from_rest_path(&widget).map_err(|e| format!("Failed to locate widget '{}': {:#?}", widget, e))?;
+    let instance = normalize_instance(instance);
+    if matches!(widget.widget_source, WidgetSource::User | WidgetSource::UserPlugin(_)) {
+        let preset = WidgetPreset::read(&PathBuf::from(&path)).map_err(|e| format!("Failed to read widget preset '{}': {:#?}", path, e))?;
+
+        let mut fieldstate: Map<String, Value> = widget.fieldstate(instance.as_deref()).into_iter().collect();
+        let result = preset.apply(&widget.fields(), &mut fieldstate);
+        if result.applied.is_empty() {
+            return Err(format!("Preset '{}' has no values for the fields of widget '{}'", path, widget.rest_path));
+        }
+
+        write_fieldstate(&widget, instance.as_deref(), &fieldstate)?;
+        return Ok(result);
+    }
+
+    return Err("Cannot import fieldstate of system or plugin widgets".into());
+}
+
+/* ================================================================================================================== */
+
+#[tauri::command]
+pub async fn get_widget_instances<R: Runtime>(_app: tauri::AppHandle<R>, widget: String) -> Result<Vec<String>, String> {
+    let widget = get_widget_from_rest_path(&widget).map_err(|e| format!("Failed to locate widget '{}': {:#?}", widget, e))?;
+    return Ok(widget.instances());
+}
+
+#[tauri::command]
+pub async fn create_widget_instance<R: Runtime>(_app: tauri::AppHandle<R>, widget: String, instance: String, copy_from: Option<String>) -> Result<(), String> {
+    let widget = get_widget_from_rest_path(&widget).map_err(|e| format!("Failed to locate widget '{}': {:#?}", widget, e))?;
+    if matches!(widget.widget_source, WidgetSource::User | WidgetSource::UserPlugin(_)) {
+        let fieldstate_path = widget.fieldstate_path(Some(&instance)).map_err(|e| format!("{:#?}", e))?;
+        if fieldstate_path.exists() {
+            return Err(format!("Instance '{}' already exists", instance));
+        }
+
+        let instances_path = widget.instances_path();
+        if !instances_path.exists() {
+            fs::create_dir_all(&instances_path).map_err(|e| format!("Failed to create widget instances folder '{:?}': {:#?}", instances_path, e))?;
+        }
+
+        let fieldstate = widget.fieldstate(normalize_instance(copy_from).as_deref());
+        let data = serde_json::to_string_pretty(&fieldstate).map_err(|e| format!("Failed to serialize fieldstate: {:#?}", e))?;
+        fs::write(&fieldstate_path, data).map_err(|e| format!("Failed to write widget '{:?}' fieldstate file: {:#?}", fieldstate_path, e))?;
+        return Ok(());
+    }
+
+    return Err("Cannot create instances of system or plugin widgets".into());
+}
+
+#[tauri::command]
+pub async fn rename_widget_instance<R: Runtime>(_app: tauri::AppHandle<R>, widget: String, instance: String, new_name: String) -> Result<(), String> {
+    let widget = get_widget_from_rest_path(&widget).map_err(|e| format!("Failed to locate widget '{}': {:#?}", widget, e))?;
+    if matches!(widget.widget_source, WidgetSource::User | WidgetSource::UserPlugin(_)) {
+        let fieldstate_path = widget.fieldstate_path(Some(&instance)).map_err(|e| format!("{:#?}", e))?;
+        let new_fieldstate_path = widget.fieldstate_path(Some(&new_name)).map_err(|e| format!("{:#?}", e))?;
+        if !fieldstate_path.is_file() {
+            return Err(format!("Instance '{}' does not exist", instance));
+        } else if new_fieldstate_path.exists() {
+            return Err(format!("Instance '{}' already exists", new_name));
+        }
+
+        fs::rename(&fieldstate_path, &new_fieldstate_path).map_err(|e| format!("Failed to rename instance '{}' to '{}': {:#?}", instance, new_name, e))?;
+        return Ok(());
+    }
+
+    return Err("Cannot rename instances of system or plugin widgets".into());
+}
+
+#[tauri::command]
+pub async fn delete_widget_instance<R: Runtime>(_app: tauri::AppHandle<R>, widget: String, instance: String) -> Result<(), String> {
+    let widget = get_widget_from_rest_path(&widget).map_err(|e| format!("Failed to locate widget '{}': {:#?}", widget, e))?;
+    if matches!(widget.widget_source, WidgetSource::User | WidgetSource::UserPlugin(_)) {
+        let fieldstate_path = widget.fieldstate_path(Some(&instance)).map_err(|e| format!("{:#?}", e))?;
+        if !fieldstate_path.is_file() {
+            return Err(format!("Instance '{}' does not exist", instance));
+        }
+
+        fs::remove_file(&fieldstate_path).map_err(|e| format!("Failed to delete instance '{}': {:#?}", instance, e))?;
+        return Ok(());
+    }
+
+    return Err("Cannot delete instances of system or plugin widgets".into());
+}
+
+/* ================================================================================================================== */
+
+#[tauri::command]
+pub async fn export_widget<R: Runtime>(_app: tauri::AppHandle<R>, widget: String, path: String, include_fieldstate: bool) -> Result<(), String> {
+    let widget = get_widget_from_rest_path(&widget).map_err(|e| format!("Failed to locate widget '{}': {:#?}", widget, e))?;
+    let archive = pack_widget(&widget, include_fieldstate)?;
+
+    let mut archive_path = PathBuf::from(path);
+    if archive_path.extension().is_none() {
+        archive_path.set_extension(ARCHIVE_EXTENSION);
+    }
+
+    archive.write(&archive_path).map_err(|e| format!("Failed to write widget archive '{:?}': {:#?}", archive_path, e))?;
+    return Ok(());
+}
+
+#[tauri::command]
+pub async fn preview_widget_archive<R: Runtime>(_app: tauri::AppHandle<R>, path: String) -> Result<WidgetArchivePreview, String> {
+    let archive = WidgetArchive::read(&PathBuf::from(&path)).map_err(|e| format!("Failed to read widget archive '{}': {:#?}", path, e))?;
+    let user_widgets_path = properties::get_app_path(AppPaths::UniChatUserWidgets);
+    let preview = archive.preview(&user_widgets_path).map_err(|e| format!("Invalid widget archive '{}': {:#?}", path, e))?;
+
+    return Ok(preview);
+}
+
+#[tauri::command]
+pub async fn import_widget_archive<R: Runtime>(_app: tauri::AppHandle<R>, path: String, name: Option<String>, overwrite: bool) -> Result<WidgetMetadata, String> {
+    let archive = WidgetArchive::read(&PathBuf::from(&path)).map_err(|e| format!("Failed to read widget archive '{}': {:#?}", path, e))?;
+    let name = name.filter(|n| !n.is_empty()).unwrap_or(archive.name.clone());
+    if is_reserved_widget_name(&name) {
+        return Err(format!("Widget name '{}' is already used by a system or plugin widget", name));
+    }
+
+    let user_widgets_path = properties::get_app_path(AppPaths::UniChatUserWidgets);
+    archive.install(&user_widgets_path, &name, overwrite).map_err(|e| format!("Failed to import widget '{}': {:#?}", name, e))?;
+
+    reload_user_widgets().map_err(|e| format!("Failed to reload user widgets: {:#?}", e))?;
+    return get_widget_from_rest_path(&name).map_err(|e| format!("Failed to locate widget '{}': {:#?}", name, e));
+}
+
+/* ================================================================================================================== */
+
+#[tauri::command]
+pub async fn fork_widget<R: Runtime>(_app: tauri::AppHandle<R>, widget: String, name: String, overwrite: bool) -> Result<WidgetMetadata, String> {
+    let widget = get_widget_from_rest_path(&widget).map_err(|e| format!("Failed to locate widget '{}': {:#?}", widget, e))?;
+    let user_widgets_path = properties::get_app_path(AppPaths::UniChatUserWidgets);
+
+    if is_reserved_widget_name(&name) {
+        return Err(format!("Widget name '{}' is already used by a system or plugin widget", name));
+    }
+
+    // Re-forking only replaces forks of the same widget, the fork fieldstate is kept.
+    let fork_path = user_widgets_path.join(&name);
+    if overwrite && fork_path.exists() && fork::load_record(&fork_path).is_none_or(|r| r.source != widget.rest_path) {
+        return Err(format!("Widget '{}' is not a fork of '{}'", name, widget.rest_path));
+    }
+
+    let archive = pack_widget(&widget, !overwrite)?;
+    let fork_path = archive.install(&user_widgets_path, &name, overwrite).map_err(|e| format!("Failed to fork widget '{}': {:#?}", widget.rest_path, e))?;
+
+    let record = WidgetForkRecord {
+        source: widget.rest_path.clone(),
+        source_version: widget.manifest.as_ref().and_then(|m| m.version.clone()),
+        forked_at: get_current_timestamp().map_err(|e| format!("{:#?}", e))?,
+        files: fork::hash_widget_files(widget.path()).map_err(|e| format!("Failed to hash widget '{}' files: {:#?}", widget.rest_path, e))?
+    };
+    fork::write_record(&fork_path, &record).map_err(|e| format!("Failed to write fork record of widget '{}': {:#?}", name, e))?;
+
+    reload_user_widgets().map_err(|e| format!("Failed to reload user widgets: {:#?}", e))?;
+    return get_widget_from_rest_path(&name).map_err(|e| format!("Failed to locate widget '{}': {:#?}", name, e));
+}
+
+#[tauri::command]
+pub async fn get_widget_fork_changes<R: Runtime>(_app: tauri::AppHandle<R>, widget: String) -> Result<Vec<WidgetForkChange>, String> {
+    let widget = get_widget_from_rest_path(&widget).map_err(|e| format!("Failed to locate widget '{}': {:#?}", widget, e))?;
+    let record = fork::load_record(widget.path()).ok_or(format!("Widget '{}' is not a fork", widget.rest_path))?;
+    let source = get_widget_from_rest_path(&record.source).map_err(|e| format!("Failed to locate source widget '{}': {:#?}", record.source, e))?;
+
+    let changes = fork::get_changes(&record, source.path(), widget.path()).map_err(|e| format!("Failed to compare widget '{}' with '{}': {:#?}", widget.rest_path, source.rest_path, e))?;
+    return Ok(changes);
+}
+
 /* ================================================================================================================== */
 
 #[tauri::command]
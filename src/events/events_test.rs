@@ -0,0 +1,80 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use anyhow::anyhow;
+use serde_json::json;
+
+use crate::events::add_interceptor;
+use crate::events::intercept;
+use crate::events::remove_interceptor;
+use crate::events::unichat::UniChatClearEventPayload;
+use crate::events::unichat::UniChatEvent;
+
+fn clear_event(timestamp: i64) -> UniChatEvent {
+    return UniChatEvent::Clear(UniChatClearEventPayload { platform: None, timestamp: timestamp });
+}
+
+fn timestamp(event: &Option<UniChatEvent>) -> Option<i64> {
+    return match event {
+        Some(UniChatEvent::Clear(payload)) => Some(payload.timestamp),
+        _ => None
+    };
+}
+
+#[test]
+fn test_interceptors() {
+    // Lower priority, runs after the doubling below.
+    let add = add_interceptor("test", -1, |event| {
+        let UniChatEvent::Clear(mut payload) = event else {
+            return Ok(Some(event));
+        };
+
+        payload.timestamp += 1;
+        return Ok(Some(UniChatEvent::Clear(payload)));
+    }).unwrap();
+    let double = add_interceptor("test", 10, |event| {
+        let UniChatEvent::Clear(mut payload) = event else {
+            return Ok(Some(event));
+        };
+
+        payload.timestamp *= 2;
+        return Ok(Some(UniChatEvent::Clear(payload)));
+    }).unwrap();
+    let failing = add_interceptor("test", 5, |_event| Err(anyhow!("failed"))).unwrap();
+    assert_eq!(timestamp(&intercept(clear_event(5))), Some(11));
+
+    let cancel = add_interceptor("test", 0, |event| {
+        let UniChatEvent::Clear(payload) = &event else {
+            return Ok(Some(event));
+        };
+
+        return Ok(if payload.timestamp > 100 { None } else { Some(event) });
+    }).unwrap();
+    assert_eq!(timestamp(&intercept(clear_event(10))), Some(21));
+    assert_eq!(timestamp(&intercept(clear_event(60))), None);
+
+    for id in [add, double, failing, cancel] {
+        remove_interceptor(id).unwrap();
+    }
+    assert_eq!(timestamp(&intercept(clear_event(60))), Some(60));
+}
+
+#[test]
+fn test_set_flag() {
+    let mut custom = UniChatEvent::Custom(json!({ "name": "points" }));
+    assert!(custom.set_flag("unichat:plugin_emitted", Some(String::from("points"))));
+    let UniChatEvent::Custom(data) = custom else {
+        unreachable!();
+    };
+    assert_eq!(data, json!({ "name": "points", "flags": { "unichat:plugin_emitted": "points" } }));
+
+    assert!(!UniChatEvent::Custom(json!("text")).set_flag("unichat:plugin_emitted", None));
+    assert!(!clear_event(0).set_flag("unichat:plugin_emitted", None));
+}
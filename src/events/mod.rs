@@ -9,9 +9,12 @@
  ******************************************************************************/
 
 use std::collections::VecDeque;
+use std::sync::Arc;
 use std::sync::LazyLock;
 use std::sync::OnceLock;
 use std::sync::RwLock;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 
 use anyhow::anyhow;
 use anyhow::Error;
@@ -20,6 +23,7 @@ use unichat::UniChatEvent;
 
 use crate::events::unichat::UNICHAT_FLAG_EMULATOR_GENERATED;
 
+#[cfg(test)] mod events_test;
 pub mod unichat;
 
 const ONCE_LOCK_NAME: &str = "Events::INSTANCE";
@@ -28,6 +32,20 @@ static INSTANCE: OnceLock<broadcast::Sender<UniChatEvent>> = OnceLock::new();
 const MAX_CAPACITY: usize = 50;
 static LATEST_EVENTS_CACHE: LazyLock<RwLock<VecDeque<UniChatEvent>>> = LazyLock::new(|| RwLock::new(VecDeque::with_capacity(MAX_CAPACITY)));
 
+/// Receives an event before broadcast, returns the event to emit (changed or not) or `None` to cancel it.
+pub type EventInterceptor = dyn Fn(UniChatEvent) -> Result<Option<UniChatEvent>, Error> + Send + Sync;
+
+struct RegisteredInterceptor {
+    id: u64,
+    owner: String,
+    priority: i32,
+    interceptor: Arc<EventInterceptor>
+}
+
+const INTERCEPTORS_LAZY_LOCK_KEY: &str = "Events::INTERCEPTORS";
+static INTERCEPTORS: LazyLock<RwLock<Vec<RegisteredInterceptor>>> = LazyLock::new(|| RwLock::new(Vec::new()));
+static NEXT_INTERCEPTOR_ID: AtomicU64 = AtomicU64::new(1);
+
 fn event_is_emulated(event: &UniChatEvent) -> bool {
     return match event {
         UniChatEvent::RemoveMessage(payload) => payload.flags.contains_key(UNICHAT_FLAG_EMULATOR_GENERATED),
@@ -93,8 +111,56 @@ pub fn subscribe() -> Result<broadcast::Receiver<UniChatEvent>, Error> {
     return Ok(instance.subscribe());
 }
 
+/// Interceptors with higher priority run first, the ones with the same priority run in registration order.
+pub fn add_interceptor<F>(owner: &str, priority: i32, interceptor: F) -> Result<u64, Error>
+where
+    F: Fn(UniChatEvent) -> Result<Option<UniChatEvent>, Error> + Send + Sync + 'static
+{
+    let mut interceptors = INTERCEPTORS.write().map_err(|_| anyhow!("{} lock poisoned", INTERCEPTORS_LAZY_LOCK_KEY))?;
+    let id = NEXT_INTERCEPTOR_ID.fetch_add(1, Ordering::SeqCst);
+    let position = interceptors.iter().position(|i| i.priority < priority).unwrap_or(interceptors.len());
+    interceptors.insert(position, RegisteredInterceptor { id, owner: owner.to_string(), priority, interceptor: Arc::new(interceptor) });
+
+    return Ok(id);
+}
+
+pub fn remove_interceptor(interceptor_id: u64) -> Result<(), Error> {
+    let mut interceptors = INTERCEPTORS.write().map_err(|_| anyhow!("{} lock poisoned", INTERCEPTORS_LAZY_LOCK_KEY))?;
+    interceptors.retain(|i| i.id != interceptor_id);
+
+    return Ok(());
+}
+
+/// Runs the event through the interceptors, a failing interceptor is skipped and the event goes on unchanged.
+fn intercept(mut event: UniChatEvent) -> Option<UniChatEvent> {
+    let interceptors: Vec<(u64, String, Arc<EventInterceptor>)>;
+    if let Ok(registered) = INTERCEPTORS.read() {
+        interceptors = registered.iter().map(|i| (i.id, i.owner.clone(), i.interceptor.clone())).collect();
+    } else {
+        interceptors = Vec::new();
+    }
+
+    for (id, owner, interceptor) in interceptors {
+        match interceptor(event.clone()) {
+            Ok(Some(intercepted)) => event = intercepted,
+            Ok(None) => {
+                log::debug!("Event cancelled by interceptor '{}' of '{}'", id, owner);
+                return None;
+            }
+            Err(err) => {
+                log::error!("An error occurred on event interceptor '{}' of '{}': {}", id, owner, err);
+            }
+        }
+    }
+
+    return Some(event);
+}
+
 pub fn emit(event: UniChatEvent) -> Result<(), Error> {
     let instance = INSTANCE.get().ok_or(anyhow!("{} was not initialized", ONCE_LOCK_NAME))?;
+    let Some(event) = intercept(event) else {
+        return Ok(());
+    };
 
     let s = instance.send(event)?;
     log::debug!("Event emitted to {} subscribers", s);
@@ -42,6 +42,22 @@ pub enum UniChatEvent {
     Custom(serde_json::Value)
 }
 
+/// Every `UniChatEvent` type tag, keep in sync with the enum above.
+pub const UNICHAT_EVENT_TYPES: [&str; 12] = [
+    "unichat:clear",
+    "unichat:remove_message",
+    "unichat:remove_author",
+    "unichat:message",
+    "unichat:donate",
+    "unichat:sponsor",
+    "unichat:sponsor_gift",
+    "unichat:raid",
+    "unichat:redemption",
+    "unichat:gift",
+    "unichat:userstore_update",
+    "unichat:custom"
+];
+
 /* <============================================================================================> */
 
 pub const UNICHAT_FLAG_TWITCH_STREAK_DAYS: &str = "unichat:twitch_streak_days";
@@ -56,6 +72,7 @@ pub const UNICHAT_FLAG_YOUTUBE_SUPERCHAT_SECONDARY_BACKGROUND_COLOR: &str = "uni
 pub const UNICHAT_FLAG_YOUTUBE_SUPERCHAT_SECONDARY_TEXT_COLOR: &str = "unichat:youtube_superchat_secondary_text_color";
 
 pub const UNICHAT_FLAG_EMULATOR_GENERATED: &str = "unichat:emulator_generated";
+pub const UNICHAT_FLAG_PLUGIN_EMITTED: &str = "unichat:plugin_emitted";
 
 /* <============================================================================================> */
 
@@ -66,15 +83,19 @@ pub enum UniChatPlatform {
     Other(String)
 }
 
-impl Serialize for UniChatPlatform {
-    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let s = match self {
+impl UniChatPlatform {
+    pub fn as_str(&self) -> &str {
+        return match self {
             UniChatPlatform::YouTube => "youtube",
             UniChatPlatform::Twitch => "twitch",
             UniChatPlatform::Other(v) => v.as_str()
         };
+    }
+}
 
-        return serializer.serialize_str(s);
+impl Serialize for UniChatPlatform {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        return serializer.serialize_str(self.as_str());
     }
 }
 
@@ -401,4 +422,81 @@ impl UniChatEvent {
     pub fn userstore_update(key: String, value: Option<String>) -> Self {
         return UniChatEvent::UserstoreUpdate(UniChatUserstoreUpdateEventPayload { key, value });
     }
+
+    /// The serialized `type` tag, one of [`UNICHAT_EVENT_TYPES`].
+    pub fn event_type(&self) -> &'static str {
+        return match self {
+            UniChatEvent::Clear(_) => "unichat:clear",
+            UniChatEvent::RemoveMessage(_) => "unichat:remove_message",
+            UniChatEvent::RemoveAuthor(_) => "unichat:remove_author",
+            UniChatEvent::Message(_) => "unichat:message",
+            UniChatEvent::Donate(_) => "unichat:donate",
+            UniChatEvent::Sponsor(_) => "unichat:sponsor",
+            UniChatEvent::SponsorGift(_) => "unichat:sponsor_gift",
+            UniChatEvent::Raid(_) => "unichat:raid",
+            UniChatEvent::Redemption(_) => "unichat:redemption",
+            UniChatEvent::Gift(_) => "unichat:gift",
+            UniChatEvent::UserstoreUpdate(_) => "unichat:userstore_update",
+            UniChatEvent::Custom(_) => "unichat:custom"
+        };
+    }
+
+    pub fn platform(&self) -> Option<&UniChatPlatform> {
+        return match self {
+            UniChatEvent::Clear(payload) => payload.platform.as_ref(),
+            UniChatEvent::RemoveMessage(payload) => Some(&payload.platform),
+            UniChatEvent::RemoveAuthor(payload) => Some(&payload.platform),
+            UniChatEvent::Message(payload) => Some(&payload.platform),
+            UniChatEvent::Donate(payload) => Some(&payload.platform),
+            UniChatEvent::Sponsor(payload) => Some(&payload.platform),
+            UniChatEvent::SponsorGift(payload) => Some(&payload.platform),
+            UniChatEvent::Raid(payload) => Some(&payload.platform),
+            UniChatEvent::Redemption(payload) => Some(&payload.platform),
+            UniChatEvent::Gift(payload) => Some(&payload.platform),
+            UniChatEvent::UserstoreUpdate(_) | UniChatEvent::Custom(_) => None
+        };
+    }
+
+    /// Author id and username of events sent by someone.
+    pub fn author(&self) -> Option<(&str, Option<&str>)> {
+        return match self {
+            UniChatEvent::RemoveAuthor(payload) => Some((&payload.author_id, None)),
+            UniChatEvent::Message(payload) => Some((&payload.author_id, payload.author_username.as_deref())),
+            UniChatEvent::Donate(payload) => Some((&payload.author_id, payload.author_username.as_deref())),
+            UniChatEvent::Sponsor(payload) => Some((&payload.author_id, payload.author_username.as_deref())),
+            UniChatEvent::SponsorGift(payload) => Some((&payload.author_id, payload.author_username.as_deref())),
+            UniChatEvent::Raid(payload) => Some((&payload.author_id, payload.author_username.as_deref())),
+            UniChatEvent::Redemption(payload) => Some((&payload.author_id, payload.author_username.as_deref())),
+            UniChatEvent::Gift(payload) => Some((&payload.author_id, payload.author_username.as_deref())),
+            _ => None
+        };
+    }
+
+    /// Sets a flag on events that carry flags, custom events get it on their `flags` object. Returns `false` otherwise.
+    pub fn set_flag(&mut self, key: &str, value: Option<String>) -> bool {
+        let flags = match self {
+            UniChatEvent::RemoveMessage(payload) => &mut payload.flags,
+            UniChatEvent::RemoveAuthor(payload) => &mut payload.flags,
+            UniChatEvent::Message(payload) => &mut payload.flags,
+            UniChatEvent::Donate(payload) => &mut payload.flags,
+            UniChatEvent::Sponsor(payload) => &mut payload.flags,
+            UniChatEvent::SponsorGift(payload) => &mut payload.flags,
+            UniChatEvent::Raid(payload) => &mut payload.flags,
+            UniChatEvent::Redemption(payload) => &mut payload.flags,
+            UniChatEvent::Gift(payload) => &mut payload.flags,
+            UniChatEvent::Custom(serde_json::Value::Object(data)) => {
+                let flags = data.entry("flags").or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+                let Some(flags) = flags.as_object_mut() else {
+                    return false;
+                };
+
+                flags.insert(key.to_string(), serde_json::to_value(value).unwrap_or_default());
+                return true;
+            },
+            _ => return false
+        };
+
+        flags.insert(key.to_string(), value);
+        return true;
+    }
 }
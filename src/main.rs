@@ -31,6 +31,7 @@ use crate::utils::properties::AppPaths;
 include!(concat!(env!("CARGO_MANIFEST_DIR"), "/target/gen/metadata.rs"));
 
 mod axum;
+mod catalog;
 mod commands;
 mod currency;
 mod events;
@@ -203,11 +204,20 @@ fn main() {
             commands::get_releases,
             commands::get_system_hosts,
             commands::get_third_party_licenses,
+            commands::catalog::get_catalog,
+            commands::catalog::install_catalog_item,
             commands::currency::get_currencies,
             commands::emulator::dispatch_emulated_event,
             commands::gallery::get_gallery_items,
             commands::gallery::upload_gallery_items,
+            commands::plugins::acknowledge_plugin_permissions,
+            commands::plugins::get_plugin_setting_fields,
+            commands::plugins::get_plugin_settings,
             commands::plugins::get_plugins,
+            commands::plugins::reload_plugin,
+            commands::plugins::set_plugin_settings,
+            commands::plugins::toggle_plugin_state,
+            commands::plugins::unload_plugin,
             commands::store::settings_get_item,
             commands::store::settings_get_items,
             commands::store::settings_set_item,
@@ -225,9 +235,21 @@ fn main() {
             commands::scrapers::toggle_scraper_webview,
             commands::userstore::get_userstore,
             commands::userstore::set_userstore,
+            commands::widgets::create_widget_instance,
+            commands::widgets::delete_widget_instance,
+            commands::widgets::export_widget,
+            commands::widgets::export_widget_preset,
+            commands::widgets::fork_widget,
             commands::widgets::get_widget_fields,
             commands::widgets::get_widget_fieldstate,
+            commands::widgets::get_widget_fork_changes,
+            commands::widgets::get_widget_instances,
             commands::widgets::get_widgets,
+            commands::widgets::import_widget_archive,
+            commands::widgets::import_widget_preset,
+            commands::widgets::preview_widget_archive,
+            commands::widgets::rename_widget_instance,
+            commands::widgets::reset_widget_fieldstate,
             commands::widgets::set_widget_fieldstate,
         ])
         .run(tauri::generate_context!())
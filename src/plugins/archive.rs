@@ -0,0 +1,114 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use anyhow::Error;
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::plugins::is_valid_plugin_name;
+use crate::plugins::manifest::load_manifest;
+use crate::utils::base64;
+use crate::utils::safe_guard_path;
+
+pub const PLUGIN_ARCHIVE_EXTENSION: &str = "ucplugin";
+const ARCHIVE_FORMAT: &str = "unichat-plugin";
+const ARCHIVE_FORMAT_VERSION: u32 = 1;
+const MAX_ARCHIVE_SIZE: u64 = 64 * 1024 * 1024;
+
+/// A plugin packed as a single JSON file, same layout as the widget archives.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginArchive {
+    pub format: String,
+    pub format_version: u32,
+    pub name: String,
+    pub unichat_version: String,
+    pub files: IndexMap<String, String>
+}
+
+impl PluginArchive {
+    pub fn from_slice(data: &[u8]) -> Result<Self, Error> {
+        if data.len() as u64 > MAX_ARCHIVE_SIZE {
+            return Err(anyhow!("Archive is larger than {} MiB", MAX_ARCHIVE_SIZE / 1024 / 1024));
+        }
+
+        let archive: Self = serde_json::from_slice(data)?;
+        if archive.format != ARCHIVE_FORMAT {
+            return Err(anyhow!("File is not a plugin archive"));
+        } else if archive.format_version > ARCHIVE_FORMAT_VERSION {
+            return Err(anyhow!("Archive format version {} is not supported", archive.format_version));
+        } else if !is_valid_plugin_name(&archive.name) {
+            return Err(anyhow!("Invalid plugin name '{}'", archive.name));
+        } else if !archive.files.contains_key("manifest.yaml") {
+            return Err(anyhow!("Archive does not contain a 'manifest.yaml'"));
+        } else if !archive.files.contains_key("data/main.lua") {
+            return Err(anyhow!("Archive does not contain a 'data/main.lua' entrypoint"));
+        }
+
+        return Ok(archive);
+    }
+
+    fn extract(&self, target_path: &PathBuf) -> Result<(), Error> {
+        for (relative_path, content) in &self.files {
+            let file_path = safe_guard_path(target_path, relative_path)?;
+            let content = base64::decode(content).map_err(|e| anyhow!("Invalid content for file '{}': {}", relative_path, e))?;
+
+            if let Some(parent) = file_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            fs::write(&file_path, content)?;
+        }
+
+        return Ok(());
+    }
+
+    /// Installs the archive as `plugins_path/name`, the manifest is validated before replacing an existing plugin.
+    pub fn install(&self, plugins_path: &Path, overwrite: bool) -> Result<PathBuf, Error> {
+        let plugins_path = plugins_path.to_path_buf();
+        let plugin_path = safe_guard_path(&plugins_path, &self.name)?;
+        if plugin_path.exists() && !overwrite {
+            return Err(anyhow!("Plugin '{}' already exists", self.name));
+        }
+
+        // Dot-prefixed folders are ignored by the plugins loader.
+        let staging_path = safe_guard_path(&plugins_path, &format!(".{}.import", self.name))?;
+        if staging_path.exists() {
+            fs::remove_dir_all(&staging_path)?;
+        }
+        fs::create_dir_all(&staging_path)?;
+
+        let result = self.extract(&staging_path).and_then(|_| load_manifest(&staging_path)).and_then(|manifest| {
+            if manifest.name != self.name {
+                return Err(anyhow!("Manifest name '{}' does not match plugin name '{}'", manifest.name, self.name));
+            }
+
+            return Ok(());
+        });
+
+        if let Err(err) = result {
+            let _ = fs::remove_dir_all(&staging_path);
+            return Err(err);
+        }
+
+        if plugin_path.exists() {
+            fs::remove_dir_all(&plugin_path)?;
+        }
+        fs::rename(&staging_path, &plugin_path)?;
+
+        return Ok(plugin_path);
+    }
+}
@@ -0,0 +1,60 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::fs;
+
+use indexmap::IndexMap;
+
+use crate::plugins::archive::PluginArchive;
+use crate::utils::base64;
+use crate::utils::test_utils::temp_dir;
+
+fn archive(name: &str, manifest_name: &str) -> PluginArchive {
+    let mut files = IndexMap::new();
+    files.insert(String::from("manifest.yaml"), base64::encode(format!("name: {}\nversion: 1.0.0\ndependencies: []\n", manifest_name)));
+    files.insert(String::from("data/main.lua"), base64::encode("return {}"));
+
+    return PluginArchive {
+        format: String::from("unichat-plugin"),
+        format_version: 1,
+        name: String::from(name),
+        unichat_version: String::from("1.5.0"),
+        files: files
+    };
+}
+
+#[test]
+fn test_from_slice_validates_archive() {
+    let raw = serde_json::to_vec(&archive("my-plugin", "my-plugin")).unwrap();
+    assert_eq!(PluginArchive::from_slice(&raw).unwrap().name, "my-plugin");
+
+    let mut invalid = archive("my-plugin", "my-plugin");
+    invalid.files.shift_remove("data/main.lua");
+    assert!(PluginArchive::from_slice(&serde_json::to_vec(&invalid).unwrap()).is_err());
+
+    assert!(PluginArchive::from_slice(&serde_json::to_vec(&archive("../evil", "evil")).unwrap()).is_err());
+    assert!(PluginArchive::from_slice(br#"{ "format": "unichat-widget" }"#).is_err());
+}
+
+#[test]
+fn test_install() {
+    let plugins_path = temp_dir("plugin-archive-install");
+
+    let plugin_path = archive("my-plugin", "my-plugin").install(&plugins_path, false).unwrap();
+    assert_eq!(fs::read_to_string(plugin_path.join("data").join("main.lua")).unwrap(), "return {}");
+    assert!(archive("my-plugin", "my-plugin").install(&plugins_path, false).is_err());
+    archive("my-plugin", "my-plugin").install(&plugins_path, true).unwrap();
+
+    assert!(archive("other-plugin", "my-plugin").install(&plugins_path, false).is_err());
+    assert!(!plugins_path.join("other-plugin").exists());
+    assert!(!plugins_path.join(".other-plugin.import").exists());
+
+    let _ = fs::remove_dir_all(&plugins_path);
+}
@@ -0,0 +1,178 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use anyhow::Error;
+
+use crate::utils::semver;
+
+pub const UNICHAT_DEPENDENCY: &str = "unichat";
+
+pub fn parse_dependencies(raw_dependencies: &Vec<String>) -> Result<Vec<(String, semver::VersionRange)>, Error> {
+    let mut dependencies: Vec<(String, semver::VersionRange)> = Vec::new();
+
+    for dep in raw_dependencies {
+        let parts: Vec<&str> = dep.splitn(2, '@').collect();
+        if parts.len() != 2 {
+            return Err(anyhow!("Invalid dependency format: '{}'. Expected format is 'name@version_req'", dep));
+        }
+
+        let name = parts[0].trim().to_string();
+        let version = parts[1].trim();
+        let version_req = semver::VersionRange::parse(version)?;
+
+        dependencies.push((name, version_req));
+    }
+
+    return Ok(dependencies);
+}
+
+/* ============================================================================================== */
+
+#[derive(Clone, Debug)]
+pub struct DependencyNode {
+    pub name: String,
+    pub version: semver::Version,
+    /// `None` when the manifest dependencies could not be parsed.
+    pub dependencies: Option<Vec<(String, semver::VersionRange)>>,
+    pub disabled: bool
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct LoadPlan {
+    /// Every plugin name, dependencies always come before their dependents (except inside cycles).
+    pub order: Vec<String>,
+    /// Plugins that must be marked as invalid, with the reasons.
+    pub errors: HashMap<String, Vec<String>>
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum VisitState {
+    Visiting,
+    Visited
+}
+
+fn visit(name: &str, nodes: &HashMap<&str, &DependencyNode>, states: &mut HashMap<String, VisitState>, stack: &mut Vec<String>, plan: &mut LoadPlan) {
+    match states.get(name) {
+        Some(VisitState::Visited) => return,
+        Some(VisitState::Visiting) => {
+            let start = stack.iter().position(|n| n == name).unwrap_or(0);
+            let mut cycle: Vec<String> = stack[start..].to_vec();
+            cycle.push(name.to_string());
+
+            let message = format!("Dependency cycle detected: {}", cycle.join(" -> "));
+            for member in &stack[start..] {
+                plan.errors.entry(member.clone()).or_default().push(message.clone());
+            }
+
+            return;
+        }
+        None => {}
+    }
+
+    let Some(node) = nodes.get(name) else {
+        return;
+    };
+
+    states.insert(name.to_string(), VisitState::Visiting);
+    stack.push(name.to_string());
+
+    for (dependency, _) in node.dependencies.iter().flatten() {
+        if nodes.contains_key(dependency.as_str()) {
+            visit(dependency, nodes, states, stack, plan);
+        }
+    }
+
+    stack.pop();
+    states.insert(name.to_string(), VisitState::Visited);
+    plan.order.push(name.to_string());
+}
+
+/// Sorts the plugins so dependencies load first and collects missing, incompatible, disabled and cyclic dependencies.
+pub fn resolve_load_order(nodes: &[DependencyNode]) -> LoadPlan {
+    let mut plan = LoadPlan::default();
+    let by_name: HashMap<&str, &DependencyNode> = nodes.iter().map(|n| (n.name.as_str(), n)).collect();
+
+    for node in nodes {
+        let Some(dependencies) = &node.dependencies else {
+            plan.errors.entry(node.name.clone()).or_default().push(String::from("Invalid dependencies declaration"));
+            continue;
+        };
+
+        for (dependency, version_req) in dependencies {
+            if dependency == UNICHAT_DEPENDENCY {
+                continue;
+            }
+
+            let message = match by_name.get(dependency.as_str()) {
+                None => format!("Required plugin '{}' ({}) is not installed", dependency, version_req),
+                Some(found) if !version_req.matches(&found.version) => {
+                    format!("Required plugin '{}' version '{}' does not satisfy the installed version '{}'", dependency, version_req, found.version)
+                }
+                Some(found) if found.disabled => format!("Required plugin '{}' is disabled", dependency),
+                Some(_) => continue
+            };
+
+            plan.errors.entry(node.name.clone()).or_default().push(message);
+        }
+    }
+
+    let mut states: HashMap<String, VisitState> = HashMap::new();
+    let mut stack: Vec<String> = Vec::new();
+    for node in nodes {
+        visit(&node.name, &by_name, &mut states, &mut stack, &mut plan);
+    }
+
+    // A plugin whose dependency is invalid can not start either, the order guarantees a single pass is enough.
+    for name in plan.order.clone() {
+        if plan.errors.contains_key(&name) {
+            continue;
+        }
+
+        let Some(node) = by_name.get(name.as_str()) else {
+            continue;
+        };
+
+        let invalid: Vec<&String> = node.dependencies.iter().flatten().map(|(d, _)| d).filter(|d| plan.errors.contains_key(d.as_str())).collect();
+        for dependency in invalid {
+            let message = format!("Required plugin '{}' is invalid", dependency);
+            plan.errors.entry(name.clone()).or_default().push(message);
+        }
+    }
+
+    return plan;
+}
+
+/// Plugins depending on `name`, directly or through another plugin, each one listed after its own dependencies.
+pub fn find_dependents(name: &str, nodes: &[DependencyNode]) -> Vec<String> {
+    let depends_on = |node: &DependencyNode, dependency: &str| node.dependencies.iter().flatten().any(|(d, _)| d == dependency);
+
+    let mut dependents: Vec<&DependencyNode> = Vec::new();
+    let mut queue = vec![name.to_string()];
+    while let Some(current) = queue.pop() {
+        for node in nodes {
+            if node.name != name && depends_on(node, &current) && !dependents.iter().any(|d| d.name == node.name) {
+                dependents.push(node);
+                queue.push(node.name.clone());
+            }
+        }
+    }
+
+    // Inside a cycle no dependent is ready, the first one is taken so the loop always ends.
+    let mut ordered = Vec::new();
+    while !dependents.is_empty() {
+        let ready = dependents.iter().position(|n| !dependents.iter().any(|other| depends_on(n, &other.name))).unwrap_or(0);
+        ordered.push(dependents.remove(ready).name.clone());
+    }
+
+    return ordered;
+}
@@ -0,0 +1,129 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use crate::plugins::dependencies::find_dependents;
+use crate::plugins::dependencies::parse_dependencies;
+use crate::plugins::dependencies::resolve_load_order;
+use crate::plugins::dependencies::DependencyNode;
+use crate::utils::semver;
+
+fn node(name: &str, version: &str, dependencies: &[&str]) -> DependencyNode {
+    let dependencies: Vec<String> = dependencies.iter().map(|d| d.to_string()).collect();
+
+    return DependencyNode {
+        name: String::from(name),
+        version: semver::Version::parse(version).unwrap(),
+        dependencies: parse_dependencies(&dependencies).ok(),
+        disabled: false
+    };
+}
+
+fn position(order: &[String], name: &str) -> usize {
+    return order.iter().position(|n| n == name).unwrap();
+}
+
+#[test]
+fn test_dependencies_load_first() {
+    let plan = resolve_load_order(&[
+        node("overlay", "1.0.0", &["unichat@1.5.0", "points@[1.0.0,2.0.0)"]),
+        node("points", "1.2.0", &["storage@1.0.0"]),
+        node("storage", "1.0.0", &[])
+    ]);
+
+    assert!(plan.errors.is_empty());
+    assert_eq!(plan.order.len(), 3);
+    assert!(position(&plan.order, "storage") < position(&plan.order, "points"));
+    assert!(position(&plan.order, "points") < position(&plan.order, "overlay"));
+}
+
+#[test]
+fn test_missing_and_incompatible_dependencies() {
+    let plan = resolve_load_order(&[
+        node("overlay", "1.0.0", &["points@[2.0.0,)"]),
+        node("points", "1.2.0", &[]),
+        node("alerts", "1.0.0", &["sounds@1.0.0"]),
+        node("chat", "1.0.0", &["overlay@1.0.0"])
+    ]);
+
+    assert_eq!(plan.order.len(), 4);
+    assert!(plan.errors["overlay"][0].contains("does not satisfy the installed version '1.2.0'"));
+    assert!(plan.errors["alerts"][0].contains("'sounds'"));
+    assert!(plan.errors["alerts"][0].contains("is not installed"));
+    assert_eq!(plan.errors["chat"], vec![String::from("Required plugin 'overlay' is invalid")]);
+    assert!(!plan.errors.contains_key("points"));
+}
+
+#[test]
+fn test_cycles_are_invalid() {
+    let plan = resolve_load_order(&[
+        node("a", "1.0.0", &["b@1.0.0"]),
+        node("b", "1.0.0", &["c@1.0.0"]),
+        node("c", "1.0.0", &["a@1.0.0"]),
+        node("d", "1.0.0", &["c@1.0.0"]),
+        node("e", "1.0.0", &[])
+    ]);
+
+    assert_eq!(plan.order.len(), 5);
+    assert_eq!(plan.errors["a"], vec![String::from("Dependency cycle detected: a -> b -> c -> a")]);
+    assert!(plan.errors.contains_key("b"));
+    assert!(plan.errors.contains_key("c"));
+    assert_eq!(plan.errors["d"], vec![String::from("Required plugin 'c' is invalid")]);
+    assert!(!plan.errors.contains_key("e"));
+}
+
+#[test]
+fn test_disabled_and_unparsable_dependencies() {
+    let mut points = node("points", "1.0.0", &[]);
+    points.disabled = true;
+
+    let plan = resolve_load_order(&[
+        node("overlay", "1.0.0", &["points@1.0.0"]),
+        points,
+        node("broken", "1.0.0", &["no-version"]),
+        node("alerts", "1.0.0", &["broken@1.0.0"])
+    ]);
+
+    assert_eq!(plan.errors["overlay"], vec![String::from("Required plugin 'points' is disabled")]);
+    assert!(!plan.errors.contains_key("points"));
+    assert!(plan.errors.contains_key("broken"));
+    assert_eq!(plan.errors["alerts"], vec![String::from("Required plugin 'broken' is invalid")]);
+}
+
+#[test]
+fn test_find_dependents() {
+    let nodes = [
+        node("overlay", "1.0.0", &["points@1.0.0", "storage@1.0.0"]),
+        node("points", "1.2.0", &["storage@1.0.0"]),
+        node("storage", "1.0.0", &["unichat@1.5.0"]),
+        node("alerts", "1.0.0", &["unichat@1.5.0"]),
+        node("badges", "1.0.0", &["alerts@1.0.0"])
+    ];
+
+    // Transitive dependents come after the plugins they depend on.
+    assert_eq!(find_dependents("storage", &nodes), vec!["points", "overlay"]);
+    assert_eq!(find_dependents("points", &nodes), vec!["overlay"]);
+    assert_eq!(find_dependents("alerts", &nodes), vec!["badges"]);
+    assert!(find_dependents("overlay", &nodes).is_empty());
+    assert!(find_dependents("missing", &nodes).is_empty());
+}
+
+#[test]
+fn test_find_dependents_with_cycles() {
+    let nodes = [
+        node("a", "1.0.0", &["b@1.0.0"]),
+        node("b", "1.0.0", &["a@1.0.0"]),
+        node("c", "1.0.0", &["b@1.0.0"])
+    ];
+
+    let dependents = find_dependents("a", &nodes);
+    assert_eq!(dependents.len(), 2);
+    assert!(!dependents.contains(&String::from("a")));
+    assert!(position(&dependents, "b") < position(&dependents, "c"));
+}
@@ -13,6 +13,8 @@ use std::sync::Arc;
 
 use anyhow::Error;
 
+use crate::events;
+use crate::plugins::UniChatPlugin;
 use crate::plugins::get_plugin;
 use crate::plugins::instance::env::unichat_api::UniChatAPI;
 use crate::plugins::instance::env::unichat_currency::UniChatCurrencyModule;
@@ -27,21 +29,25 @@ use crate::plugins::instance::env::unichat_logger::UniChatLoggerModule;
 use crate::plugins::instance::env::unichat_strings::UniChatStringsModule;
 use crate::plugins::instance::env::unichat_time::UniChatTimeModule;
 use crate::plugins::instance::env::unichat_utils::UniChatUtilsModule;
+use crate::plugins::instance::env::unichat_websocket::UniChatWebSocketModule;
 use crate::plugins::instance::env::unichat_yaml::UniChatYamlModule;
-use crate::plugins::runtime;
 use crate::utils::safe_guard_path;
 use crate::utils::semver::Version;
 
+#[cfg(test)] mod shared_modules_test;
 mod shared_modules;
 mod unichat_api;
 mod unichat_currency;
 mod unichat_event;
+#[cfg(test)] mod unichat_http_test;
 mod unichat_http;
 mod unichat_json;
 mod unichat_logger;
 mod unichat_strings;
 mod unichat_time;
 mod unichat_utils;
+#[cfg(test)] mod unichat_websocket_test;
+mod unichat_websocket;
 mod unichat_yaml;
 mod utils;
 
@@ -84,7 +90,12 @@ fn scoped_modules_require(lua: &mlua::Lua, plugin_env: &mlua::Table, plugin_name
     if module == "unichat:currency" {
         return UniChatCurrencyModule::new(lua);
     } else if module == "unichat:http" {
-        return UniChatHttpModule::new(lua);
+        let plugin = get_plugin(plugin_name).map_err(mlua::Error::external)?;
+        if !plugin.permissions.has_http() {
+            return Err(mlua::Error::runtime(format!("Plugin '{}' requires an 'http:<host>' permission to use 'unichat:http'", plugin_name)));
+        }
+
+        return UniChatHttpModule::new(lua, plugin_name, plugin.permissions.clone());
     } else if module == "unichat:json" {
         return UniChatJsonModule::new(lua);
     } else if module == "unichat:logger" {
@@ -97,6 +108,13 @@ fn scoped_modules_require(lua: &mlua::Lua, plugin_env: &mlua::Table, plugin_name
         return UniChatUtilsModule::new(lua);
     } else if module == "unichat:yaml" {
         return UniChatYamlModule::new(lua);
+    } else if module == "unichat:websocket" {
+        let plugin = get_plugin(plugin_name).map_err(mlua::Error::external)?;
+        if !plugin.permissions.has_http() {
+            return Err(mlua::Error::runtime(format!("Plugin '{}' requires an 'http:<host>' permission to use 'unichat:websocket'", plugin_name)));
+        }
+
+        return UniChatWebSocketModule::new(lua, plugin_name, plugin.permissions.clone());
     }
 
     let manifest = get_plugin(plugin_name).map_err(mlua::Error::external)?;
@@ -123,7 +141,7 @@ fn create_require_fn(lua: &mlua::Lua, plugin_name: &str) -> Result<mlua::Functio
 
         let plugin_env = plugin.get_plugin_env().map_err(mlua::Error::external)?;
         if module.contains(':') && !module.starts_with("unichat:") {
-            if let Some(shared_module) = shared_modules::get(&module).map_err(mlua::Error::external)? {
+            if let Some(shared_module) = shared_modules::get(lua, &plugin, &module).map_err(mlua::Error::external)? {
                 return Ok(shared_module);
             }
         } else {
@@ -140,21 +158,19 @@ fn create_require_fn(lua: &mlua::Lua, plugin_name: &str) -> Result<mlua::Functio
 
 /* ========================================================================== */
 
-pub fn load_env(plugin_name:&str, plugin_version: &Version, plugin_env: mlua::Table) -> Result<(), Error> {
-    let lua = runtime::get()?;
-
+pub fn load_env(lua: &mlua::Lua, plugin_name:&str, plugin_version: &Version, plugin_env: mlua::Table) -> Result<(), Error> {
     /* <======================[ LUA Standard Library ]======================> */
-    let print_func = create_print_fn(&lua, plugin_name)?;
+    let print_func = create_print_fn(lua, plugin_name)?;
     plugin_env.set("print", print_func)?;
 
-    let require_fn = create_require_fn(&lua, plugin_name)?;
+    let require_fn = create_require_fn(lua, plugin_name)?;
     plugin_env.set("require", require_fn)?;
     /* <====================[ End LUA Standard Library ]====================> */
 
     /* <====================[ UniChat Standard Library ]====================> */
     plugin_env.set(PLUGIN_NAME_KEY, plugin_name.to_string())?;
     plugin_env.set(PLUGIN_VERSION_KEY, plugin_version.to_string())?;
-    plugin_env.set(UNICHAT_API_KEY, UniChatAPI::new(plugin_name))?;
+    plugin_env.set(UNICHAT_API_KEY, UniChatAPI::new(plugin_name)?)?;
     plugin_env.set(UNICHAT_EVENT_KEY, LuaUniChatEventFactory)?;
     plugin_env.set(UNICHAT_PLATFORM_KEY, LuaUniChatPlatformFactory)?;
     plugin_env.set(UNICHAT_AUTHOR_TYPE_KEY, LuaUniChatAuthorTypeFactory)?;
@@ -169,3 +185,31 @@ pub fn load_env(plugin_name:&str, plugin_version: &Version, plugin_env: mlua::Ta
 
     return Ok(());
 }
+
+/* ========================================================================== */
+
+/// Stops the plugin background tasks (event listeners and timers included), removes its event interceptors, RPC methods, HTTP routes and shared modules.
+pub fn unload_env(plugin: &UniChatPlugin) {
+    plugin.clear_rpc_methods();
+    plugin.clear_http_routes();
+
+    for interceptor_id in plugin.take_interceptors() {
+        if let Err(err) = events::remove_interceptor(interceptor_id) {
+            log::error!("Failed to remove event interceptor '{}' of plugin '{}': {:?}", interceptor_id, plugin.name, err);
+        }
+    }
+
+    for task in plugin.take_tasks() {
+        task.abort();
+    }
+
+    for timer in plugin.take_timers() {
+        timer.abort();
+    }
+
+    for module_name in plugin.take_exposed_modules() {
+        if let Err(err) = shared_modules::remove(&module_name) {
+            log::error!("Failed to remove shared module '{}' of plugin '{}': {:?}", module_name, plugin.name, err);
+        }
+    }
+}
@@ -15,16 +15,92 @@ use std::sync::RwLock;
 use anyhow::anyhow;
 use anyhow::Error;
 
+use crate::plugins::get_plugin;
+use crate::plugins::limits;
+use crate::plugins::limits::ExecutionLimits;
+use crate::plugins::UniChatPlugin;
+
+/// Guards against self referencing tables when copying values between LUA states.
+const MAX_COPY_DEPTH: usize = 32;
+
 const SHARED_MODULES_LAZY_LOCK_KEY: &str = "Plugins::SHARED_MODULES";
 static SHARED_MODULES: LazyLock<RwLock<HashMap<String, mlua::Value>>> = LazyLock::new(|| RwLock::new(HashMap::new()));
 
-pub fn get(module_name: &str) -> Result<Option<mlua::Value>, Error> {
+/// Calls a function of another plugin state, arguments and results are copied across the boundary.
+fn create_proxy(lua: &mlua::Lua, owner: &str, owner_lua: mlua::WeakLua, function: mlua::Function) -> Result<mlua::Function, mlua::Error> {
+    let owner = owner.to_string();
+    let proxy = lua.create_function(move |lua, args: mlua::MultiValue| -> mlua::Result<mlua::MultiValue> {
+        let Some(target_lua) = owner_lua.try_upgrade() else {
+            return Err(mlua::Error::runtime(format!("Plugin '{}' that exposed this module was unloaded", owner)));
+        };
+
+        let args = args.iter().map(|arg| copy_value(&target_lua, arg, None, 0)).collect::<Result<mlua::MultiValue, mlua::Error>>()?;
+        let results = limits::call_plugin(&owner, ExecutionLimits::CALLBACK, || function.call::<mlua::MultiValue>(args))?;
+
+        return results.iter().map(|result| copy_value(lua, result, Some((&owner, &target_lua)), 0)).collect();
+    })?;
+
+    return Ok(proxy);
+}
+
+/// Copies a value into `lua`, tables are copied by value and functions of `owner` become proxies.
+/// Without an owner functions are rejected, the caller can not hand its own functions to another plugin.
+pub fn copy_value(lua: &mlua::Lua, value: &mlua::Value, owner: Option<(&str, &mlua::Lua)>, depth: usize) -> Result<mlua::Value, mlua::Error> {
+    if depth > MAX_COPY_DEPTH {
+        return Err(mlua::Error::runtime(format!("Shared module values can not be nested deeper than {} levels", MAX_COPY_DEPTH)));
+    }
+
+    return match value {
+        mlua::Value::Nil | mlua::Value::Boolean(_) | mlua::Value::Integer(_) | mlua::Value::Number(_) | mlua::Value::LightUserData(_) => Ok(value.clone()),
+        mlua::Value::String(string) => Ok(mlua::Value::String(lua.create_string(string.as_bytes())?)),
+        mlua::Value::Table(table) => {
+            let copy = lua.create_table()?;
+            for pair in table.pairs::<mlua::Value, mlua::Value>() {
+                let (key, value) = pair?;
+                copy.raw_set(copy_value(lua, &key, owner, depth + 1)?, copy_value(lua, &value, owner, depth + 1)?)?;
+            }
+
+            Ok(mlua::Value::Table(copy))
+        },
+        mlua::Value::Function(function) => {
+            let Some((owner_name, owner_lua)) = owner else {
+                return Err(mlua::Error::runtime("Functions can not be passed to shared modules"));
+            };
+
+            Ok(mlua::Value::Function(create_proxy(lua, owner_name, owner_lua.weak(), function.clone())?))
+        },
+        other => Err(mlua::Error::runtime(format!("Values of type '{}' can not cross shared modules", other.type_name())))
+    };
+}
+
+/// Returns a copy of the module living in the `lua` state of the requiring plugin.
+///
+/// A proxy call runs the owner state while the caller state is still locked, so plugins may only require
+/// modules of their own dependencies. Dependency cycles are rejected on load, which keeps every thread
+/// locking plugin states in the same order and cross plugin calls can not deadlock.
+pub fn get(lua: &mlua::Lua, plugin: &UniChatPlugin, module_name: &str) -> Result<Option<mlua::Value>, Error> {
     if module_name.is_empty() || !module_name.contains(":") {
         return Err(anyhow!("Invalid module name '{}'. Module names must be in the format 'plugin_name:module_name'", module_name));
     }
 
-    let shared_modules = SHARED_MODULES.read().map_err(|_| anyhow!("{} lock poisoned", SHARED_MODULES_LAZY_LOCK_KEY))?;
-    return Ok(shared_modules.get(module_name).cloned());
+    let owner = module_name.split(':').next().unwrap_or_default();
+    if owner != plugin.name && !plugin.dependencies.iter().any(|(dependency, _)| dependency == owner) {
+        return Err(anyhow!("Plugin '{}' must list '{}' in its dependencies to require '{}'", plugin.name, owner, module_name));
+    }
+
+    let module = {
+        let shared_modules = SHARED_MODULES.read().map_err(|_| anyhow!("{} lock poisoned", SHARED_MODULES_LAZY_LOCK_KEY))?;
+        shared_modules.get(module_name).cloned()
+    };
+
+    let Some(module) = module else {
+        return Ok(None);
+    };
+
+    let owner_lua = get_plugin(owner)?.get_lua();
+    let copy = copy_value(lua, &module, Some((owner, &owner_lua)), 0)?;
+
+    return Ok(Some(copy));
 }
 
 pub fn add(module_name: String, module_table: mlua::Value) -> Result<(), Error> {
@@ -0,0 +1,61 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use crate::plugins::instance::env::shared_modules::copy_value;
+
+fn expose(owner_lua: &mlua::Lua, consumer_lua: &mlua::Lua, code: &str) -> mlua::Value {
+    let module: mlua::Value = owner_lua.load(code).eval().unwrap();
+    return copy_value(consumer_lua, &module, Some(("owner", owner_lua)), 0).unwrap();
+}
+
+#[test]
+fn test_module_is_copied_between_states() {
+    let owner_lua = mlua::Lua::new();
+    let consumer_lua = mlua::Lua::new();
+
+    let module = expose(&owner_lua, &consumer_lua, "M = { name = 'counter', values = { 1, 2, 3 }, count = 0, add = function(a, b) return a + b end } return M");
+    consumer_lua.globals().set("module", module).unwrap();
+
+    let result: (String, i64, i64) = consumer_lua.load("module.count = 10 return module.name, #module.values, module.add(2, 3)").eval().unwrap();
+    assert_eq!(result, (String::from("counter"), 3, 5));
+
+    // Copies are detached, changing them does not affect the module of the owner.
+    let count: i64 = owner_lua.load("return M.count").eval().unwrap();
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn test_functions_do_not_cross_as_arguments() {
+    let owner_lua = mlua::Lua::new();
+    let consumer_lua = mlua::Lua::new();
+
+    let module = expose(&owner_lua, &consumer_lua, "return { call = function(fn) return fn() end, echo = function(t) return t end }");
+    consumer_lua.globals().set("module", module).unwrap();
+
+    let echoed: String = consumer_lua.load("return module.echo({ value = 'ok' }).value").eval().unwrap();
+    assert_eq!(echoed, "ok");
+    assert!(consumer_lua.load("return module.call(function() return 1 end)").exec().is_err());
+
+    let cyclic: mlua::Value = owner_lua.load("local t = {} t.self = t return t").eval().unwrap();
+    assert!(copy_value(&consumer_lua, &cyclic, None, 0).is_err());
+}
+
+#[test]
+fn test_unloaded_owner() {
+    let owner_lua = mlua::Lua::new();
+    let consumer_lua = mlua::Lua::new();
+
+    let module = expose(&owner_lua, &consumer_lua, "return { ping = function() return 'pong' end }");
+    consumer_lua.globals().set("module", module).unwrap();
+    drop(owner_lua);
+
+    let err = consumer_lua.load("return module.ping()").exec().unwrap_err();
+    assert!(err.to_string().contains("was unloaded"));
+}
@@ -0,0 +1,53 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use anyhow::anyhow;
+use anyhow::Error;
+use mlua::LuaSerdeExt as _;
+
+use crate::events;
+use crate::plugins::get_plugin;
+use crate::plugins::instance::env::unichat_event::LuaUniChatEvent;
+use crate::plugins::limits;
+use crate::plugins::limits::ExecutionLimits;
+
+/// Registers `callback(event)` as an event interceptor, it returns the event to emit or nil to cancel it.
+pub fn add_interceptor(lua: &mlua::Lua, plugin_name: &str, callback: mlua::Function, priority: i32) -> Result<u64, Error> {
+    let plugin = get_plugin(plugin_name)?;
+    let lua = lua.weak();
+    let owner = plugin_name.to_string();
+
+    let interceptor_id = events::add_interceptor(plugin_name, priority, move |event| {
+        let lua = lua.try_upgrade().ok_or(anyhow!("LUA state of plugin '{}' was unloaded", owner))?;
+        let table = lua.to_value(&event)?;
+
+        let result = limits::call_plugin(&owner, ExecutionLimits::INTERCEPTOR, || callback.call::<Option<LuaUniChatEvent>>(table));
+        if let Err(err) = &result {
+            log::error!(target: &format!("plugin:{}", owner), "An error occurred on UniChatAPI event interceptor callback: {}", err);
+            if let Ok(plugin) = get_plugin(&owner) {
+                plugin.add_message(format!("Event interceptor failed, event was emitted unchanged: {}", err));
+            }
+        }
+
+        return Ok(result?.map(|event| event.inner));
+    })?;
+    plugin.add_interceptor(interceptor_id);
+
+    return Ok(interceptor_id);
+}
+
+pub fn remove_interceptor(plugin_name: &str, interceptor_id: u64) -> Result<(), Error> {
+    let plugin = get_plugin(plugin_name)?;
+    if plugin.remove_interceptor(interceptor_id) {
+        events::remove_interceptor(interceptor_id)?;
+    }
+
+    return Ok(());
+}
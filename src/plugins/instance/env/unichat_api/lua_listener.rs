@@ -0,0 +1,73 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+use crate::events::unichat::UNICHAT_EVENT_TYPES;
+use crate::events::unichat::UniChatEvent;
+
+/// Listener subscription, evaluated before the event is converted to a LUA table.
+#[derive(Clone, Debug, Default)]
+pub struct LuaEventListenerFilter {
+    event_types: Option<BTreeSet<String>>,
+    platforms: Option<BTreeSet<String>>
+}
+
+impl LuaEventListenerFilter {
+    pub fn new(event_types: Option<Vec<String>>, platforms: Option<Vec<String>>) -> Result<Self, mlua::Error> {
+        if let Some(event_type) = event_types.iter().flatten().find(|t| !UNICHAT_EVENT_TYPES.contains(&t.as_str())) {
+            return Err(mlua::Error::runtime(format!("Unknown event type '{}', expected one of: {}", event_type, UNICHAT_EVENT_TYPES.join(", "))));
+        }
+
+        let event_types: Option<BTreeSet<String>> = event_types.map(|types| types.into_iter().collect());
+        let platforms: Option<BTreeSet<String>> = platforms.map(|platforms| platforms.iter().map(|p| p.trim().to_lowercase()).collect());
+
+        return Ok(Self { event_types, platforms });
+    }
+
+    /// Events without a platform (custom and userstore events) are not filtered by platform.
+    pub fn matches(&self, event: &UniChatEvent) -> bool {
+        if self.event_types.as_ref().is_some_and(|types| !types.contains(event.event_type())) {
+            return false;
+        }
+
+        if let (Some(platforms), Some(platform)) = (&self.platforms, event.platform()) {
+            if !platforms.contains(&platform.as_str().to_lowercase()) {
+                return false;
+            }
+        }
+
+        return true;
+    }
+}
+
+impl mlua::FromLua for LuaEventListenerFilter {
+    fn from_lua(value: mlua::Value, _lua: &mlua::Lua) -> mlua::Result<Self> {
+        return match value {
+            mlua::Value::Nil => Ok(Self::default()),
+            mlua::Value::Table(table) => Self::new(table.get("event_types")?, table.get("platforms")?),
+            other => Err(mlua::Error::runtime(format!("Event listener filter must be a table, got '{}'", other.type_name())))
+        };
+    }
+}
+
+impl Display for LuaEventListenerFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let describe = |items: &Option<BTreeSet<String>>| -> String {
+            return match items {
+                Some(items) => items.iter().cloned().collect::<Vec<String>>().join(", "),
+                None => String::from("all")
+            };
+        };
+
+        return write!(f, "event types: {}; platforms: {}", describe(&self.event_types), describe(&self.platforms));
+    }
+}
@@ -0,0 +1,37 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use crate::events::unichat::UniChatClearEventPayload;
+use crate::events::unichat::UniChatEvent;
+use crate::events::unichat::UniChatPlatform;
+use crate::plugins::instance::env::unichat_api::lua_listener::LuaEventListenerFilter;
+
+fn clear_event(platform: Option<UniChatPlatform>) -> UniChatEvent {
+    return UniChatEvent::Clear(UniChatClearEventPayload { platform: platform, timestamp: 0 });
+}
+
+#[test]
+fn test_filter_matches() {
+    let all = LuaEventListenerFilter::default();
+    assert!(all.matches(&clear_event(None)));
+    assert_eq!(all.to_string(), "event types: all; platforms: all");
+
+    let twitch = LuaEventListenerFilter::new(None, Some(vec![String::from(" Twitch ")])).unwrap();
+    assert!(twitch.matches(&clear_event(Some(UniChatPlatform::Twitch))));
+    assert!(!twitch.matches(&clear_event(Some(UniChatPlatform::YouTube))));
+    assert!(twitch.matches(&clear_event(None)));
+
+    let types = LuaEventListenerFilter::new(Some(vec![String::from("unichat:donate"), String::from("unichat:custom")]), None).unwrap();
+    assert!(!types.matches(&clear_event(None)));
+    assert!(types.matches(&UniChatEvent::Custom(serde_json::Value::Null)));
+    assert_eq!(types.to_string(), "event types: unichat:custom, unichat:donate; platforms: all");
+
+    assert!(LuaEventListenerFilter::new(Some(vec![String::from("donate")]), None).is_err());
+}
@@ -11,12 +11,14 @@
 use std::fs;
 use std::io::Write as _;
 
+use anyhow::anyhow;
 use anyhow::Error;
 use mlua::LuaSerdeExt as _;
 
 use crate::events;
 use crate::plugins::instance::env::unichat_event::LuaUniChatEvent;
-use crate::plugins::runtime;
+use crate::plugins::limits;
+use crate::plugins::limits::ExecutionLimits;
 use crate::scraper::UniChatScraper;
 use crate::scraper::status::ScraperStatus;
 use crate::scraper::status::ScraperStatusEvent;
@@ -44,6 +46,8 @@ fn get_table_property<R: mlua::FromLua>(table: &mlua::Table, key: &str, default_
 }
 
 pub struct LuaUniChatScraper {
+    lua: mlua::WeakLua,
+    plugin_name: String,
     id: String,
     name: String,
     editing_tooltip_message: String,
@@ -62,7 +66,7 @@ pub struct LuaUniChatScraper {
 }
 
 impl LuaUniChatScraper {
-    pub fn new(id: String, name: String, scraper_js: String, on_event: mlua::Function, opts: mlua::Table) -> Result<Self, mlua::Error> {
+    pub fn new(lua: &mlua::Lua, plugin_name: &str, id: String, name: String, scraper_js: String, on_event: mlua::Function, opts: mlua::Table) -> Result<Self, mlua::Error> {
         let editing_tooltip_message = get_table_property(&opts, "editing_tooltip_message", Some(format!("Enter {} chat url...", name)))?;
         let editing_tooltip_urls = get_table_property(&opts, "editing_tooltip_urls", Some(Vec::new()))?;
         let placeholder_text = get_table_property(&opts, "placeholder_text", Some(format!("Enter {} chat url...", name)))?;
@@ -84,6 +88,8 @@ impl LuaUniChatScraper {
         }
 
         return Ok(Self {
+            lua: lua.weak(),
+            plugin_name: plugin_name.to_string(),
             id: id,
             name: name,
             editing_tooltip_message: editing_tooltip_message,
@@ -102,6 +108,11 @@ impl LuaUniChatScraper {
         });
     }
 
+    /// The scraper outlives its plugin until unregistered, so it does not keep the plugin LUA state alive.
+    fn get_lua(&self) -> Result<mlua::Lua, Error> {
+        return self.lua.try_upgrade().ok_or(anyhow!("LUA state of plugin '{}' was unloaded", self.plugin_name));
+    }
+
     fn log_action(&self, file_name: &str, content: &impl std::fmt::Display) {
         let app_log_dir = properties::get_app_path(AppPaths::AppLog);
         let scraper_log_dir = app_log_dir.join(&self.id);
@@ -155,12 +166,12 @@ impl UniChatScraper for LuaUniChatScraper {
     }
 
     fn validate_url(&self, url: String) -> Result<String, Error> {
-        let result: String = self.validate_url.call(url)?;
+        let result: String = limits::call_plugin(&self.plugin_name, ExecutionLimits::CALLBACK, || self.validate_url.call(url))?;
         return Ok(result);
     }
 
     fn on_event(&self, event: serde_json::Value) -> Result<(), Error> {
-        let lua = runtime::get()?;
+        let lua = self.get_lua()?;
         let log_events = settings::get_scraper_events_log_level();
 
         if log_events == SettingLogEventLevel::AllEvents {
@@ -169,7 +180,7 @@ impl UniChatScraper for LuaUniChatScraper {
 
         let table = lua.to_value(&event)?;
 
-        match self.on_event.call::<Option<LuaUniChatEvent>>(table) {
+        match limits::call_plugin(&self.plugin_name, ExecutionLimits::CALLBACK, || self.on_event.call::<Option<LuaUniChatEvent>>(table)) {
             Ok(Some(parsed_event)) => {
                 let parsed = parsed_event.inner;
                 if log_events == SettingLogEventLevel::AllEvents {
@@ -200,10 +211,10 @@ impl UniChatScraper for LuaUniChatScraper {
             return Ok(());
         };
 
-        let lua = runtime::get()?;
+        let lua = self.get_lua()?;
         let table = lua.to_value(event)?;
 
-        if let Err(err) = callback.call::<()>(table) {
+        if let Err(err) = limits::call_plugin(&self.plugin_name, ExecutionLimits::CALLBACK, || callback.call::<()>(table)) {
             log::error!(target: &format!("scraper:{}", self.name), "An error occurred on '{}' scraper '{:?}' status callback: {:#?}", self.id, event.status, err);
         }
 
@@ -0,0 +1,80 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Error;
+
+use crate::plugins::get_plugin;
+use crate::plugins::limits;
+use crate::plugins::limits::ExecutionLimits;
+
+/// Smallest delay accepted, keeps `set_interval(fn, 0)` from hogging the plugin LUA state.
+const MIN_TIMER_DELAY_MS: u64 = 10;
+
+pub fn timer_delay(delay_ms: u64) -> Duration {
+    return Duration::from_millis(delay_ms.max(MIN_TIMER_DELAY_MS));
+}
+
+/// Calls the callback after each delay until the timer is done, the error of a failed callback stops it.
+pub async fn run_timer(plugin_name: &str, callback: &mlua::Function, delay: Duration, repeat: bool) -> Result<(), mlua::Error> {
+    loop {
+        tokio::time::sleep(delay).await;
+        limits::call_plugin(plugin_name, ExecutionLimits::CALLBACK, || callback.call::<()>(()))?;
+
+        if !repeat {
+            return Ok(());
+        }
+    }
+}
+
+/// Schedules the callback on the tokio runtime, a callback that fails stops its timer.
+/// Timer ids restart on every plugin instance, so the finished timer is only removed from its own instance.
+pub fn spawn_timer(plugin_name: &str, callback: mlua::Function, delay_ms: u64, repeat: bool) -> Result<u64, Error> {
+    let plugin = get_plugin(plugin_name)?;
+    let timer_id = plugin.next_timer_id();
+    let delay = timer_delay(delay_ms);
+
+    let weak_plugin = Arc::downgrade(&plugin);
+    let plugin_name = plugin_name.to_string();
+    let task = tauri::async_runtime::spawn(async move {
+        let result = run_timer(&plugin_name, &callback, delay, repeat).await;
+        if let Err(err) = &result {
+            log::error!(target: &format!("plugin:{}", plugin_name), "An error occurred on UniChatAPI timer '{}' callback: {}", timer_id, err);
+        }
+
+        let Some(plugin) = weak_plugin.upgrade() else {
+            return;
+        };
+
+        if !get_plugin(&plugin_name).is_ok_and(|current| Arc::ptr_eq(&current, &plugin)) {
+            return;
+        }
+
+        if let Err(err) = result {
+            plugin.add_message(format!("Timer '{}' stopped after an error: {}", timer_id, err));
+        }
+
+        plugin.remove_timer(timer_id);
+    });
+    plugin.add_timer(timer_id, task);
+
+    return Ok(timer_id);
+}
+
+pub fn clear_timer(plugin_name: &str, timer_id: u64) -> Result<(), Error> {
+    let plugin = get_plugin(plugin_name)?;
+    if let Some(task) = plugin.remove_timer(timer_id) {
+        task.abort();
+    }
+
+    return Ok(());
+}
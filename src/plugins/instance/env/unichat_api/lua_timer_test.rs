@@ -0,0 +1,60 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::time::Duration;
+
+use crate::plugins::instance::env::unichat_api::lua_timer::run_timer;
+use crate::plugins::instance::env::unichat_api::lua_timer::timer_delay;
+
+/// A callback counting its calls in the `calls` global, it fails on the call number `fail_at`.
+fn counter(lua: &mlua::Lua, fail_at: i64) -> mlua::Function {
+    lua.load("calls = 0").exec().unwrap();
+    let code = format!("return function() calls = calls + 1 if calls == {} then error('boom') end end", fail_at);
+    return lua.load(code).eval().unwrap();
+}
+
+fn calls(lua: &mlua::Lua) -> i64 {
+    return lua.globals().get("calls").unwrap();
+}
+
+#[test]
+fn test_timer_delay() {
+    assert_eq!(timer_delay(0), Duration::from_millis(10));
+    assert_eq!(timer_delay(5), Duration::from_millis(10));
+    assert_eq!(timer_delay(250), Duration::from_millis(250));
+}
+
+#[tokio::test]
+async fn test_timeout_runs_once() {
+    let lua = mlua::Lua::new();
+    let callback = counter(&lua, -1);
+
+    run_timer("timer-test", &callback, timer_delay(0), false).await.unwrap();
+    assert_eq!(calls(&lua), 1);
+}
+
+#[tokio::test]
+async fn test_interval_repeats_until_error() {
+    let lua = mlua::Lua::new();
+    let callback = counter(&lua, 3);
+
+    let result = tokio::time::timeout(Duration::from_secs(5), run_timer("timer-test", &callback, timer_delay(0), true)).await.unwrap();
+    assert!(result.unwrap_err().to_string().contains("boom"));
+    assert_eq!(calls(&lua), 3);
+}
+
+#[tokio::test]
+async fn test_timeout_error_is_returned() {
+    let lua = mlua::Lua::new();
+    let callback = counter(&lua, 1);
+
+    assert!(run_timer("timer-test", &callback, timer_delay(0), false).await.is_err());
+    assert_eq!(calls(&lua), 1);
+}
@@ -14,14 +14,23 @@ use std::sync::RwLock;
 use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
 
+use anyhow::Error;
 use mlua::LuaSerdeExt as _;
 
 use crate::events;
+use crate::events::unichat::UNICHAT_FLAG_PLUGIN_EMITTED;
+use crate::events::unichat::UniChatEvent;
 use crate::plugins::get_plugin;
+use crate::plugins::limits;
+use crate::plugins::limits::ExecutionLimits;
+use crate::plugins::permissions::PluginPermission;
+use crate::plugins::routes;
+use crate::plugins::rpc;
 use crate::plugins::instance::env::shared_modules;
+use crate::plugins::instance::env::unichat_api::lua_listener::LuaEventListenerFilter;
 use crate::plugins::instance::env::unichat_api::lua_scraper::LuaUniChatScraper;
+use crate::plugins::instance::env::unichat_event::LuaUniChatEvent;
 use crate::plugins::instance::env::utils::table_deep_readonly;
-use crate::plugins::runtime;
 use crate::scraper;
 use crate::scraper::UniChatScraper;
 use crate::shared_emotes;
@@ -30,26 +39,33 @@ use crate::utils::render_emitter;
 use crate::utils::safe_guard_path;
 use crate::utils::userstore;
 
+mod lua_interceptor;
+#[cfg(test)] mod lua_listener_test;
+mod lua_listener;
 mod lua_scraper;
+#[cfg(test)] mod lua_timer_test;
+mod lua_timer;
 
 /* ================================================================================================================== */
 
 pub struct UniChatAPI {
     plugin_name: String,
-    event_listeners: Arc<RwLock<Vec<(u64, mlua::Function)>>>,
+    event_listeners: Arc<RwLock<Vec<(u64, LuaEventListenerFilter, mlua::Function)>>>,
     next_listener_id: Arc<AtomicU64>
 }
 
 impl UniChatAPI {
-    pub fn new(plugin_name: &str) -> Self {
+    pub fn new(plugin_name: &str) -> Result<Self, Error> {
+        let plugin = get_plugin(plugin_name)?;
         let name = plugin_name.to_string();
-        let listeners: Arc<RwLock<Vec<(u64, mlua::Function)>>> = Arc::new(RwLock::new(Vec::new()));
+        let listeners: Arc<RwLock<Vec<(u64, LuaEventListenerFilter, mlua::Function)>>> = Arc::new(RwLock::new(Vec::new()));
         let next_id = Arc::new(AtomicU64::new(0));
 
         let logger_name = plugin_name.to_string();
         let listeners_clone = listeners.clone();
-        tauri::async_runtime::spawn(async move {
-            let mut rx = events::subscribe().unwrap();
+        let lua = plugin.get_lua().weak();
+        let mut rx = events::subscribe()?;
+        let task = tauri::async_runtime::spawn(async move {
 
             loop {
                 let received = rx.recv().await;
@@ -58,16 +74,29 @@ impl UniChatAPI {
                     Ok(event) => {
                         let callbacks: Vec<mlua::Function>;
                         if let Ok(listeners) = listeners_clone.read() {
-                            callbacks = listeners.clone().iter().map(|(_id, function)| function.clone()).collect();
+                            callbacks = listeners.iter().filter(|(_id, filter, _function)| filter.matches(&event)).map(|(_id, _filter, function)| function.clone()).collect();
                         } else {
                             callbacks = Vec::new();
                         }
 
-                        let lua = runtime::get().unwrap();
-                        let table = lua.to_value(&event).unwrap();
+                        if callbacks.is_empty() {
+                            continue;
+                        }
+
+                        let Some(lua) = lua.try_upgrade() else {
+                            break;
+                        };
+
+                        let table = match lua.to_value(&event) {
+                            Ok(table) => table,
+                            Err(err) => {
+                                log::error!(target: &format!("plugin:{}", logger_name), "Failed to convert event for UniChatAPI event listeners: {}", err);
+                                continue;
+                            }
+                        };
 
                         for callback in callbacks.iter() {
-                            if let Err(err) = callback.call::<()>(table.clone()) {
+                            if let Err(err) = limits::call_plugin(&logger_name, ExecutionLimits::CALLBACK, || callback.call::<()>(table.clone())) {
                                 log::error!(target: &format!("plugin:{}", logger_name), "An error occurred on UniChatAPI event listener callback: {}", err);
                             }
                         }
@@ -82,12 +111,13 @@ impl UniChatAPI {
                 }
             }
         });
+        plugin.add_task(task);
 
-        return Self {
+        return Ok(Self {
             plugin_name: name,
             event_listeners: listeners,
             next_listener_id: next_id
-        };
+        });
     }
 }
 
@@ -97,16 +127,18 @@ impl mlua::UserData for UniChatAPI {
             return Ok(UNICHAT_VERSION.to_string());
         });
 
-        methods.add_method("register_scraper", |_lua, this, (id, name, scraper_js_path, on_event, opts): (String, String, String, mlua::Function, mlua::Table)| {
+        methods.add_method("register_scraper", |lua, this, (id, name, scraper_js_path, on_event, opts): (String, String, String, mlua::Function, mlua::Table)| {
             let plugin = get_plugin(&this.plugin_name).map_err(mlua::Error::external)?;
+            plugin.require_permission(&PluginPermission::Scrapers).map_err(mlua::Error::external)?;
 
             let scraper_js_path = safe_guard_path(&plugin.get_data_path(), &scraper_js_path).map_err(mlua::Error::external)?;
             let scraper_js_content = fs::read_to_string(scraper_js_path).map_err(mlua::Error::external)?;
-            let scraper = LuaUniChatScraper::new(id, name, scraper_js_content, on_event, opts).map_err(mlua::Error::external)?;
+            let scraper = LuaUniChatScraper::new(lua, &this.plugin_name, id, name, scraper_js_content, on_event, opts).map_err(mlua::Error::external)?;
 
             let scraper: Arc<dyn UniChatScraper + Send + Sync> = Arc::new(scraper);
             let scraper_id = scraper.id().to_string();
             scraper::register_scraper(scraper).map_err(mlua::Error::external)?;
+            plugin.add_scraper(&scraper_id);
             plugin.add_message(format!("Registered scraper '{}'.", scraper_id));
 
             return Ok(());
@@ -140,26 +172,29 @@ impl mlua::UserData for UniChatAPI {
             let plugin_name = this.plugin_name.clone();
             let key = format!("{}:{}", plugin_name, module_name);
 
-            if let Err(err) = shared_modules::add(key, module_table) {
+            if let Err(err) = shared_modules::add(key.clone(), module_table) {
                 log::error!(target: &format!("plugin:{}", plugin_name), "Failed to expose shared module '{}' for plugin '{}': {}", module_name, plugin_name, err);
                 let msg = format!("Failed to expose shared module '{}': {}", module_name, err);
                 plugin.add_message(msg.clone());
                 return Err(mlua::Error::external(msg));
             }
 
+            plugin.add_exposed_module(&key);
             plugin.add_message(format!("Exposed shared module '{}'.", module_name));
 
             return Ok(());
         });
 
-        methods.add_method("add_event_listener", |_lua, this, callback: mlua::Function| {
+        methods.add_method("add_event_listener", |_lua, this, (callback, filter): (mlua::Function, LuaEventListenerFilter)| {
             let plugin = get_plugin(&this.plugin_name).map_err(mlua::Error::external)?;
 
             let id = this.next_listener_id.fetch_add(1, Ordering::SeqCst);
+            let message = format!("Added event listener '{:?}' ({}).", id, filter);
             let mut listeners = this.event_listeners.write().map_err(|_| mlua::Error::external("event_listeners lock poisoned"))?;
-            listeners.push((id, callback));
+            listeners.push((id, filter, callback));
+            drop(listeners);
 
-            plugin.add_message(format!("Added event listener '{:?}'.", id));
+            plugin.add_message(message);
 
             return Ok(id);
         });
@@ -168,14 +203,89 @@ impl mlua::UserData for UniChatAPI {
             let plugin = get_plugin(&this.plugin_name).map_err(mlua::Error::external)?;
 
             let mut listeners = this.event_listeners.write().map_err(|_| mlua::Error::external("event_listeners lock poisoned"))?;
-            listeners.retain(|(id, _)| *id != listener_id);
+            listeners.retain(|(id, _filter, _function)| *id != listener_id);
 
             plugin.add_message(format!("Removed event listener '{:?}'.", listener_id));
 
             return Ok(());
         });
 
+        methods.add_method("add_event_interceptor", |lua, this, (callback, priority): (mlua::Function, Option<i32>)| {
+            let plugin = get_plugin(&this.plugin_name).map_err(mlua::Error::external)?;
+            plugin.require_permission(&PluginPermission::EventsIntercept).map_err(mlua::Error::external)?;
+            let id = lua_interceptor::add_interceptor(lua, &this.plugin_name, callback, priority.unwrap_or(0)).map_err(mlua::Error::external)?;
+            plugin.add_message(format!("Added event interceptor '{:?}'.", id));
+
+            return Ok(id);
+        });
+
+        methods.add_method("remove_event_interceptor", |_lua, this, interceptor_id: u64| {
+            let plugin = get_plugin(&this.plugin_name).map_err(mlua::Error::external)?;
+            lua_interceptor::remove_interceptor(&this.plugin_name, interceptor_id).map_err(mlua::Error::external)?;
+            plugin.add_message(format!("Removed event interceptor '{:?}'.", interceptor_id));
+
+            return Ok(());
+        });
+
+        methods.add_method("emit", |_lua, this, event: LuaUniChatEvent| {
+            let plugin = get_plugin(&this.plugin_name).map_err(mlua::Error::external)?;
+            plugin.require_permission(&PluginPermission::EventsEmit).map_err(mlua::Error::external)?;
+
+            let mut event = event.inner;
+            if let UniChatEvent::UserstoreUpdate(_) = event {
+                return Err(mlua::Error::runtime("'unichat:userstore_update' events are emitted by the userstore, use 'set_userstore_item' instead"));
+            }
+
+            event.set_flag(UNICHAT_FLAG_PLUGIN_EMITTED, Some(this.plugin_name.clone()));
+            log::debug!(target: &format!("plugin:{}", this.plugin_name), "Emitting event: {:?}", event);
+            events::emit(event).map_err(mlua::Error::external)?;
+
+            return Ok(());
+        });
+
+        methods.add_method("register_rpc_method", |_lua, this, (method_name, callback): (String, mlua::Function)| {
+            let plugin = get_plugin(&this.plugin_name).map_err(mlua::Error::external)?;
+            if !rpc::is_valid_method_name(&method_name) {
+                return Err(mlua::Error::runtime(format!("Invalid RPC method name '{}', only letters, digits, '_', '-' and '.' are allowed", method_name)));
+            }
+
+            plugin.add_rpc_method(&method_name, callback).map_err(mlua::Error::external)?;
+            plugin.add_message(format!("Registered RPC method '{}:{}'.", this.plugin_name, method_name));
+
+            return Ok(());
+        });
+
+        methods.add_method("register_http_route", |_lua, this, (method, path, handler): (String, String, mlua::Function)| {
+            let plugin = get_plugin(&this.plugin_name).map_err(mlua::Error::external)?;
+            let method = method.to_uppercase();
+            if method != "*" && (method.is_empty() || !method.chars().all(|c| c.is_ascii_alphabetic())) {
+                return Err(mlua::Error::runtime(format!("Invalid HTTP method '{}'", method)));
+            } else if !routes::is_valid_route_path(&path) {
+                return Err(mlua::Error::runtime(format!("Invalid HTTP route path '{}', it must start with '/' and may only end with '/*'", path)));
+            }
+
+            plugin.add_http_route(&method, &path, handler).map_err(mlua::Error::external)?;
+            plugin.add_message(format!("Registered HTTP route '{} {}' at '/plugin/{}{}'.", method, path, this.plugin_name, path));
+
+            return Ok(());
+        });
+
+        methods.add_method("set_timeout", |_lua, this, (callback, delay_ms): (mlua::Function, u64)| {
+            return lua_timer::spawn_timer(&this.plugin_name, callback, delay_ms, false).map_err(mlua::Error::external);
+        });
+
+        methods.add_method("set_interval", |_lua, this, (callback, interval_ms): (mlua::Function, u64)| {
+            return lua_timer::spawn_timer(&this.plugin_name, callback, interval_ms, true).map_err(mlua::Error::external);
+        });
+
+        methods.add_method("clear_timer", |_lua, this, timer_id: u64| {
+            return lua_timer::clear_timer(&this.plugin_name, timer_id).map_err(mlua::Error::external);
+        });
+
         methods.add_method("get_userstore_item", |lua, this, key: String| {
+            let plugin = get_plugin(&this.plugin_name).map_err(mlua::Error::external)?;
+            plugin.require_permission(&PluginPermission::Userstore).map_err(mlua::Error::external)?;
+
             let key = format!("{}:{}", this.plugin_name, key);
             let item: Option<String> = userstore::get_item(&key).map_err(mlua::Error::external)?;
             if let Some(item) = item {
@@ -187,6 +297,9 @@ impl mlua::UserData for UniChatAPI {
         });
 
         methods.add_method("set_userstore_item", |_lua, this, (key, value): (String, mlua::Value)| {
+            let plugin = get_plugin(&this.plugin_name).map_err(mlua::Error::external)?;
+            plugin.require_permission(&PluginPermission::Userstore).map_err(mlua::Error::external)?;
+
             let str_value: Option<String>;
             if let mlua::Value::String(lua_string) = value {
                 str_value = Some(lua_string.to_string_lossy());
@@ -201,7 +314,28 @@ impl mlua::UserData for UniChatAPI {
             return Ok(());
         });
 
-        methods.add_method("notify", |_lua, this, message: String| {
+        methods.add_method("get_setting", |lua, this, key: String| {
+            let plugin = get_plugin(&this.plugin_name).map_err(mlua::Error::external)?;
+            let settings = plugin.get_settings();
+            if let Some(value) = settings.get(&key) {
+                return lua.to_value(value);
+            }
+
+            return Ok(mlua::Value::Nil);
+        });
+
+        methods.add_method("on_setting_change", |_lua, this, callback: mlua::Function| {
+            let plugin = get_plugin(&this.plugin_name).map_err(mlua::Error::external)?;
+            plugin.add_settings_listener(callback);
+            plugin.add_message("Added settings change listener.");
+
+            return Ok(());
+        });
+
+        methods.add_method("notify",|_lua, this, message: String| {
+            let plugin = get_plugin(&this.plugin_name).map_err(mlua::Error::external)?;
+            plugin.require_permission(&PluginPermission::Notifications).map_err(mlua::Error::external)?;
+
             render_emitter::emit_notification(&this.plugin_name, &message);
             return Ok(());
         });
@@ -8,11 +8,21 @@
  * SPDX-License-Identifier: EPL-2.0
  ******************************************************************************/
 
+use std::sync::Arc;
+use std::time::Duration;
+
 use mlua::LuaSerdeExt as _;
 
+use crate::plugins::get_plugin;
+use crate::plugins::limits;
+use crate::plugins::limits::ExecutionLimits;
+use crate::plugins::permissions::PluginPermissions;
 use crate::utils::base64;
 use crate::utils::ureq;
+use crate::utils::ureq::http::Uri;
 use crate::utils::ureq::http::response::Response;
+use crate::utils::ureq::typestate::WithBody;
+use crate::utils::ureq::Body;
 use crate::utils::ureq::RequestBuilder;
 use crate::utils::ureq::ResponseExt as _;
 
@@ -94,8 +104,26 @@ impl mlua::UserData for UreqResponse {
 
 /* ============================================================================================== */
 
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Reads `timeout_ms` from the request args, falling back to `DEFAULT_TIMEOUT` when it is not set.
+pub fn request_timeout(args: Option<&mlua::Table>) -> Result<Duration, mlua::Error> {
+    let Some(args) = args else {
+        return Ok(DEFAULT_TIMEOUT);
+    };
+
+    return match args.get::<mlua::Value>("timeout_ms")? {
+        mlua::Value::Nil => Ok(DEFAULT_TIMEOUT),
+        mlua::Value::Integer(timeout_ms) if timeout_ms > 0 => Ok(Duration::from_millis(timeout_ms as u64)),
+        mlua::Value::Number(timeout_ms) if timeout_ms >= 1.0 && timeout_ms.fract() == 0.0 => Ok(Duration::from_millis(timeout_ms as u64)),
+        _ => Err(mlua::Error::external("Invalid timeout_ms. Expected a positive integer."))
+    };
+}
+
+/// Redirects are not followed, the permission check only covers the requested URL.
 fn apply_args<B>(builder: RequestBuilder<B>, args: Option<mlua::Table>) -> Result<RequestBuilder<B>, mlua::Error> {
-    let mut builder = builder;
+    let timeout = request_timeout(args.as_ref())?;
+    let mut builder = builder.config().max_redirects(0).timeout_global(Some(timeout)).build();
 
     if let Some(args) = args {
         if let Ok(headers) = args.get::<mlua::Table>("headers") {
@@ -130,18 +158,120 @@ fn apply_args<B>(builder: RequestBuilder<B>, args: Option<mlua::Table>) -> Resul
 
 /* ============================================================================================== */
 
-pub struct UniChatHttpModule;
+/// Request body converted on the LUA side, so the request itself can run on another thread.
+#[derive(Debug, PartialEq)]
+pub enum RequestBody {
+    Empty,
+    Text(String),
+    Json(serde_json::Value)
+}
+
+impl RequestBody {
+    pub fn from_lua(lua: &mlua::Lua, body: Option<mlua::Value>) -> Result<Self, mlua::Error> {
+        return match body {
+            None | Some(mlua::Value::Nil) => Ok(RequestBody::Empty),
+            Some(mlua::Value::String(s)) => Ok(RequestBody::Text(s.to_string_lossy())),
+            Some(mlua::Value::Table(t)) => Ok(RequestBody::Json(lua.from_value(mlua::Value::Table(t))?)),
+            Some(_) => Err(mlua::Error::external("Invalid body type. Expected string or table."))
+        };
+    }
+
+    fn send(self, request: RequestBuilder<WithBody>) -> Result<Response<Body>, ureq::Error> {
+        return match self {
+            RequestBody::Empty => request.send_empty(),
+            RequestBody::Text(text) => request.send(&text),
+            RequestBody::Json(value) => request.send_json(&value)
+        };
+    }
+}
+
+/// Reads the whole body on the calling thread, so `text`, `json` and `bytes` do not block once back in LUA.
+fn buffer_response(response: Response<Body>) -> Result<Response<Body>, ureq::Error> {
+    let (parts, mut body) = response.into_parts();
+    let mime_type = body.mime_type().map(String::from);
+    let charset = body.charset().map(String::from);
+    let data = body.read_to_vec()?;
+
+    let mut builder = Body::builder();
+    if let Some(mime_type) = mime_type {
+        builder = builder.mime_type(mime_type);
+    }
+
+    if let Some(charset) = charset {
+        builder = builder.charset(charset);
+    }
+
+    return Ok(Response::from_parts(parts, builder.data(data)));
+}
+
+/// Runs the request on the blocking pool and calls `callback(response, err)` when it completes.
+/// The callback is dropped when the plugin was unloaded or reloaded in the meantime.
+fn dispatch_async<F>(plugin_name: &str, callback: mlua::Function, request: F) -> Result<(), mlua::Error>
+where
+    F: FnOnce() -> Result<Response<Body>, ureq::Error> + Send + 'static
+{
+    let plugin = get_plugin(plugin_name).map_err(mlua::Error::external)?;
+    let plugin = Arc::downgrade(&plugin);
+    let plugin_name = plugin_name.to_string();
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let result = request().and_then(buffer_response);
+
+        let Some(plugin) = plugin.upgrade() else {
+            return;
+        };
+
+        if !get_plugin(&plugin_name).is_ok_and(|current| Arc::ptr_eq(&current, &plugin)) {
+            log::debug!(target: &format!("plugin:{}", plugin_name), "Dropping HTTP response, plugin was unloaded");
+            return;
+        }
+
+        let call_result = limits::call_plugin(&plugin_name, ExecutionLimits::CALLBACK, || {
+            let lua = plugin.get_lua();
+            return match result {
+                Ok(response) => callback.call::<()>((lua.create_userdata(UreqResponse { response })?, mlua::Value::Nil)),
+                Err(err) => callback.call::<()>((mlua::Value::Nil, err.to_string()))
+            };
+        });
+
+        if let Err(err) = call_result {
+            log::error!(target: &format!("plugin:{}", plugin_name), "An error occurred on UniChatHttpModule callback: {}", err);
+            plugin.add_message(format!("HTTP callback failed: {}", err));
+        }
+    });
+
+    return Ok(());
+}
+
+/* ============================================================================================== */
+
+pub struct UniChatHttpModule {
+    plugin_name: String,
+    permissions: PluginPermissions
+}
 
 impl UniChatHttpModule {
-    pub fn new (lua: &mlua::Lua) -> Result<mlua::Value, mlua::Error> {
-        let userdata = lua.create_userdata(UniChatHttpModule)?;
+    pub fn new (lua: &mlua::Lua, plugin_name: &str, permissions: PluginPermissions) -> Result<mlua::Value, mlua::Error> {
+        let plugin_name = plugin_name.to_string();
+        let userdata = lua.create_userdata(UniChatHttpModule { plugin_name, permissions })?;
         return Ok(mlua::Value::UserData(userdata));
     }
+
+    fn check_uri(&self, uri: &str) -> Result<(), mlua::Error> {
+        let parsed: Uri = uri.parse().map_err(mlua::Error::external)?;
+        let host = parsed.host().ok_or(mlua::Error::runtime(format!("Invalid URL '{}'", uri)))?;
+        if !self.permissions.allows_host(host) {
+            return Err(mlua::Error::runtime(format!("Missing 'http:{}' permission to request '{}'", host, uri)));
+        }
+
+        return Ok(());
+    }
 }
 
 impl mlua::UserData for UniChatHttpModule {
     fn add_methods<M: mlua::UserDataMethods<Self>>(methods: &mut M) {
-        methods.add_method("get", |lua, _this, (uri, args): (String, Option<mlua::Table>)| {
+        methods.add_method("get", |lua, this, (uri, args): (String, Option<mlua::Table>)| {
+            this.check_uri(&uri)?;
             let mut request = ureq::get(uri);
             request = apply_args(request, args)?;
 
@@ -150,7 +280,8 @@ impl mlua::UserData for UniChatHttpModule {
             return Ok(mlua::Value::UserData(userdata));
         });
 
-        methods.add_method("post", |lua, _this, (uri, body, args): (String, Option<mlua::Value>, Option<mlua::Table>)| {
+        methods.add_method("post", |lua, this, (uri, body, args): (String, Option<mlua::Value>, Option<mlua::Table>)| {
+            this.check_uri(&uri)?;
             let mut request = ureq::post(uri);
             request = apply_args(request, args)?;
 
@@ -177,7 +308,8 @@ impl mlua::UserData for UniChatHttpModule {
             return Ok(mlua::Value::UserData(userdata));
         });
 
-        methods.add_method("put", |lua, _this, (uri, body, args): (String, Option<mlua::Value>, Option<mlua::Table>)| {
+        methods.add_method("put", |lua, this, (uri, body, args): (String, Option<mlua::Value>, Option<mlua::Table>)| {
+            this.check_uri(&uri)?;
             let mut request = ureq::put(uri);
             request = apply_args(request, args)?;
 
@@ -204,7 +336,8 @@ impl mlua::UserData for UniChatHttpModule {
             return Ok(mlua::Value::UserData(userdata));
         });
 
-        methods.add_method("patch", |lua, _this, (uri, body, args): (String, Option<mlua::Value>, Option<mlua::Table>)| {
+        methods.add_method("patch", |lua, this, (uri, body, args): (String, Option<mlua::Value>, Option<mlua::Table>)| {
+            this.check_uri(&uri)?;
             let mut request = ureq::patch(uri);
             request = apply_args(request, args)?;
 
@@ -231,7 +364,8 @@ impl mlua::UserData for UniChatHttpModule {
             return Ok(mlua::Value::UserData(userdata));
         });
 
-        methods.add_method("delete", |lua, _this, (uri, args): (String, Option<mlua::Table>)| {
+        methods.add_method("delete", |lua, this, (uri, args): (String, Option<mlua::Table>)| {
+            this.check_uri(&uri)?;
             let mut request = ureq::delete(uri);
             request = apply_args(request, args)?;
 
@@ -240,7 +374,8 @@ impl mlua::UserData for UniChatHttpModule {
             return Ok(mlua::Value::UserData(userdata));
         });
 
-        methods.add_method("head", |lua, _this, (uri, args): (String, Option<mlua::Table>)| {
+        methods.add_method("head", |lua, this, (uri, args): (String, Option<mlua::Table>)| {
+            this.check_uri(&uri)?;
             let mut request = ureq::head(uri);
             request = apply_args(request, args)?;
 
@@ -248,5 +383,46 @@ impl mlua::UserData for UniChatHttpModule {
             let userdata = lua.create_userdata(UreqResponse { response })?;
             return Ok(mlua::Value::UserData(userdata));
         });
+
+        /* ====================================================================== */
+
+        methods.add_method("get_async", |_lua, this, (uri, args, callback): (String, Option<mlua::Table>, mlua::Function)| {
+            this.check_uri(&uri)?;
+            let request = apply_args(ureq::get(uri), args)?;
+            return dispatch_async(&this.plugin_name, callback, move || request.call());
+        });
+
+        methods.add_method("post_async", |lua, this, (uri, body, args, callback): (String, Option<mlua::Value>, Option<mlua::Table>, mlua::Function)| {
+            this.check_uri(&uri)?;
+            let request = apply_args(ureq::post(uri), args)?;
+            let body = RequestBody::from_lua(lua, body)?;
+            return dispatch_async(&this.plugin_name, callback, move || body.send(request));
+        });
+
+        methods.add_method("put_async", |lua, this, (uri, body, args, callback): (String, Option<mlua::Value>, Option<mlua::Table>, mlua::Function)| {
+            this.check_uri(&uri)?;
+            let request = apply_args(ureq::put(uri), args)?;
+            let body = RequestBody::from_lua(lua, body)?;
+            return dispatch_async(&this.plugin_name, callback, move || body.send(request));
+        });
+
+        methods.add_method("patch_async", |lua, this, (uri, body, args, callback): (String, Option<mlua::Value>, Option<mlua::Table>, mlua::Function)| {
+            this.check_uri(&uri)?;
+            let request = apply_args(ureq::patch(uri), args)?;
+            let body = RequestBody::from_lua(lua, body)?;
+            return dispatch_async(&this.plugin_name, callback, move || body.send(request));
+        });
+
+        methods.add_method("delete_async", |_lua, this, (uri, args, callback): (String, Option<mlua::Table>, mlua::Function)| {
+            this.check_uri(&uri)?;
+            let request = apply_args(ureq::delete(uri), args)?;
+            return dispatch_async(&this.plugin_name, callback, move || request.call());
+        });
+
+        methods.add_method("head_async", |_lua, this, (uri, args, callback): (String, Option<mlua::Table>, mlua::Function)| {
+            this.check_uri(&uri)?;
+            let request = apply_args(ureq::head(uri), args)?;
+            return dispatch_async(&this.plugin_name, callback, move || request.call());
+        });
     }
 }
@@ -0,0 +1,53 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::plugins::instance::env::unichat_http::DEFAULT_TIMEOUT;
+use crate::plugins::instance::env::unichat_http::RequestBody;
+use crate::plugins::instance::env::unichat_http::request_timeout;
+
+fn timeout(lua: &mlua::Lua, args: &str) -> Result<Duration, mlua::Error> {
+    let args: mlua::Table = lua.load(args).eval().unwrap();
+    return request_timeout(Some(&args));
+}
+
+#[test]
+fn test_request_timeout() {
+    let lua = mlua::Lua::new();
+    assert_eq!(request_timeout(None).unwrap(), DEFAULT_TIMEOUT);
+    assert_eq!(timeout(&lua, "return {}").unwrap(), DEFAULT_TIMEOUT);
+    assert_eq!(timeout(&lua, "return { timeout_ms = 1500 }").unwrap(), Duration::from_millis(1500));
+    assert_eq!(timeout(&lua, "return { timeout_ms = 2000.0 }").unwrap(), Duration::from_millis(2000));
+
+    assert!(timeout(&lua, "return { timeout_ms = 0 }").is_err());
+    assert!(timeout(&lua, "return { timeout_ms = -100 }").is_err());
+    assert!(timeout(&lua, "return { timeout_ms = 1.5 }").is_err());
+    assert!(timeout(&lua, "return { timeout_ms = '1000' }").is_err());
+    assert!(timeout(&lua, "return { timeout_ms = true }").is_err());
+}
+
+#[test]
+fn test_request_body_from_lua() {
+    let lua = mlua::Lua::new();
+    assert_eq!(RequestBody::from_lua(&lua, None).unwrap(), RequestBody::Empty);
+    assert_eq!(RequestBody::from_lua(&lua, Some(mlua::Value::Nil)).unwrap(), RequestBody::Empty);
+
+    let text: mlua::Value = lua.load("return 'hello'").eval().unwrap();
+    assert_eq!(RequestBody::from_lua(&lua, Some(text)).unwrap(), RequestBody::Text(String::from("hello")));
+
+    let table: mlua::Value = lua.load("return { name = 'voguh', tags = { 'a', 'b' } }").eval().unwrap();
+    assert_eq!(RequestBody::from_lua(&lua, Some(table)).unwrap(), RequestBody::Json(json!({ "name": "voguh", "tags": ["a", "b"] })));
+
+    assert!(RequestBody::from_lua(&lua, Some(mlua::Value::Boolean(true))).is_err());
+    assert!(RequestBody::from_lua(&lua, Some(mlua::Value::Integer(1))).is_err());
+}
@@ -0,0 +1,253 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use futures::SinkExt as _;
+use futures::StreamExt as _;
+use mlua::LuaSerdeExt as _;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest as _;
+use tokio_tungstenite::tungstenite::http::HeaderName;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+
+use crate::plugins::get_plugin;
+use crate::plugins::limits;
+use crate::plugins::limits::ExecutionLimits;
+use crate::plugins::permissions::PluginPermissions;
+use crate::utils::ureq::http::Uri;
+
+/// How long to wait for the server to answer a close frame before dropping the connection.
+pub const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct WebSocketCallbacks {
+    pub on_open: Option<mlua::Function>,
+    pub on_message: Option<mlua::Function>,
+    pub on_close: Option<mlua::Function>,
+    pub on_error: Option<mlua::Function>
+}
+
+/// Calls an optional callback, returns `false` when it failed so the connection is closed.
+fn call_callback(plugin_name: &str, name: &str, callback: &Option<mlua::Function>, args: impl mlua::IntoLuaMulti) -> bool {
+    let Some(callback) = callback else {
+        return true;
+    };
+
+    if let Err(err) = limits::call_plugin(plugin_name, ExecutionLimits::CALLBACK, || callback.call::<()>(args)) {
+        log::error!(target: &format!("plugin:{}", plugin_name), "An error occurred on UniChatWebSocket '{}' callback: {}", name, err);
+        if let Ok(plugin) = get_plugin(plugin_name) {
+            plugin.add_message(format!("WebSocket closed after an error on '{}': {}", name, err));
+        }
+
+        return false;
+    }
+
+    return true;
+}
+
+/// Runs the connection until either side closes it, the task is aborted when the plugin is unloaded.
+pub async fn run_connection(plugin_name: String, lua: mlua::WeakLua, request: tokio_tungstenite::tungstenite::handshake::client::Request, callbacks: WebSocketCallbacks, mut rx: mpsc::UnboundedReceiver<Message>, open: Arc<AtomicBool>) {
+    let stream = match tokio_tungstenite::connect_async(request).await {
+        Ok((stream, _response)) => stream,
+        Err(err) => {
+            call_callback(&plugin_name, "on_error", &callbacks.on_error, err.to_string());
+            call_callback(&plugin_name, "on_close", &callbacks.on_close, (mlua::Value::Nil, mlua::Value::Nil));
+            return;
+        }
+    };
+
+    let (mut write, mut read) = stream.split();
+    open.store(true, Ordering::SeqCst);
+    let mut healthy = call_callback(&plugin_name, "on_open", &callbacks.on_open, ());
+    // Set once the close frame is sent, messages received while closing do not extend it.
+    let mut close_deadline: Option<Instant> = None;
+    let mut close_frame: Option<CloseFrame> = None;
+
+    if !healthy {
+        close_deadline = Some(Instant::now() + CLOSE_TIMEOUT);
+        let _ = write.send(Message::Close(None)).await;
+    }
+
+    while healthy || close_deadline.is_some() {
+        tokio::select! {
+            outgoing = rx.recv(), if close_deadline.is_none() => {
+                let message = outgoing.unwrap_or(Message::Close(None));
+                if matches!(message, Message::Close(_)) {
+                    close_deadline = Some(Instant::now() + CLOSE_TIMEOUT);
+                }
+
+                if let Err(err) = write.send(message).await {
+                    call_callback(&plugin_name, "on_error", &callbacks.on_error, err.to_string());
+                    break;
+                }
+            }
+            incoming = read.next() => {
+                match incoming {
+                    Some(Ok(Message::Text(text))) if healthy => {
+                        healthy = call_callback(&plugin_name, "on_message", &callbacks.on_message, text.to_string());
+                    }
+                    Some(Ok(Message::Binary(data))) if healthy => {
+                        let Some(lua) = lua.try_upgrade() else {
+                            break;
+                        };
+
+                        let data = match lua.create_string(&data) {
+                            Ok(data) => data,
+                            Err(err) => {
+                                call_callback(&plugin_name, "on_error", &callbacks.on_error, err.to_string());
+                                break;
+                            }
+                        };
+
+                        healthy = call_callback(&plugin_name, "on_message", &callbacks.on_message, data);
+                    }
+                    Some(Ok(Message::Close(frame))) => {
+                        close_frame = frame;
+                        break;
+                    }
+                    Some(Ok(_)) => {}
+                    Some(Err(err)) => {
+                        call_callback(&plugin_name, "on_error", &callbacks.on_error, err.to_string());
+                        break;
+                    }
+                    None => break
+                }
+
+                if !healthy && close_deadline.is_none() {
+                    close_deadline = Some(Instant::now() + CLOSE_TIMEOUT);
+                    let _ = write.send(Message::Close(None)).await;
+                }
+            }
+            _ = tokio::time::sleep_until(close_deadline.unwrap_or_else(Instant::now)), if close_deadline.is_some() => break
+        }
+    }
+
+    open.store(false, Ordering::SeqCst);
+    let code = close_frame.as_ref().map(|frame| u16::from(frame.code));
+    let reason = close_frame.as_ref().map(|frame| frame.reason.to_string());
+    call_callback(&plugin_name, "on_close", &callbacks.on_close, (code, reason));
+}
+
+/* ============================================================================================== */
+
+struct LuaWebSocket {
+    sender: mpsc::UnboundedSender<Message>,
+    open: Arc<AtomicBool>
+}
+
+impl LuaWebSocket {
+    fn queue(&self, message: Message) -> Result<(), mlua::Error> {
+        return self.sender.send(message).map_err(|_| mlua::Error::runtime("WebSocket is closed"));
+    }
+}
+
+impl mlua::UserData for LuaWebSocket {
+    fn add_fields<F: mlua::UserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("is_open", |_lua, this| {
+            return Ok(this.open.load(Ordering::SeqCst));
+        });
+    }
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method("send", |lua, this, data: mlua::Value| {
+            match data {
+                mlua::Value::String(s) => {
+                    return this.queue(Message::text(s.to_string_lossy()));
+                }
+                mlua::Value::Table(t) => {
+                    let value: serde_json::Value = lua.from_value(mlua::Value::Table(t))?;
+                    let text = serde_json::to_string(&value).map_err(mlua::Error::external)?;
+                    return this.queue(Message::text(text));
+                }
+                _ => {
+                    return Err(mlua::Error::external("Invalid message type. Expected string or table."));
+                }
+            }
+        });
+
+        methods.add_method("close", |_lua, this, (code, reason): (Option<u16>, Option<String>)| {
+            let frame = code.map(|code| CloseFrame { code: CloseCode::from(code), reason: reason.unwrap_or_default().into() });
+            // Closing twice is a no-op, the connection may already be gone.
+            let _ = this.queue(Message::Close(frame));
+            return Ok(());
+        });
+    }
+}
+
+/* ============================================================================================== */
+
+pub struct UniChatWebSocketModule {
+    plugin_name: String,
+    permissions: PluginPermissions
+}
+
+impl UniChatWebSocketModule {
+    pub fn new (lua: &mlua::Lua, plugin_name: &str, permissions: PluginPermissions) -> Result<mlua::Value, mlua::Error> {
+        let plugin_name = plugin_name.to_string();
+        let userdata = lua.create_userdata(UniChatWebSocketModule { plugin_name, permissions })?;
+        return Ok(mlua::Value::UserData(userdata));
+    }
+
+    fn check_uri(&self, uri: &str) -> Result<(), mlua::Error> {
+        let parsed: Uri = uri.parse().map_err(mlua::Error::external)?;
+        if !matches!(parsed.scheme_str(), Some("ws") | Some("wss")) {
+            return Err(mlua::Error::runtime(format!("Invalid WebSocket URL '{}', expected a 'ws://' or 'wss://' URL", uri)));
+        }
+
+        let host = parsed.host().ok_or(mlua::Error::runtime(format!("Invalid URL '{}'", uri)))?;
+        if !self.permissions.allows_host(host) {
+            return Err(mlua::Error::runtime(format!("Missing 'http:{}' permission to connect to '{}'", host, uri)));
+        }
+
+        return Ok(());
+    }
+}
+
+impl mlua::UserData for UniChatWebSocketModule {
+    fn add_methods<M: mlua::UserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method("connect", |lua, this, (uri, opts): (String, Option<mlua::Table>)| {
+            this.check_uri(&uri)?;
+            let plugin = get_plugin(&this.plugin_name).map_err(mlua::Error::external)?;
+
+            let mut request = uri.as_str().into_client_request().map_err(mlua::Error::external)?;
+            let mut callbacks = WebSocketCallbacks { on_open: None, on_message: None, on_close: None, on_error: None };
+            if let Some(opts) = opts {
+                if let Some(headers) = opts.get::<Option<mlua::Table>>("headers")? {
+                    for pair in headers.pairs::<String, String>() {
+                        let (key, value) = pair?;
+                        let key = HeaderName::from_bytes(key.as_bytes()).map_err(mlua::Error::external)?;
+                        let value = HeaderValue::from_str(&value).map_err(mlua::Error::external)?;
+                        request.headers_mut().insert(key, value);
+                    }
+                }
+
+                callbacks.on_open = opts.get("on_open")?;
+                callbacks.on_message = opts.get("on_message")?;
+                callbacks.on_close = opts.get("on_close")?;
+                callbacks.on_error = opts.get("on_error")?;
+            }
+
+            let (sender, rx) = mpsc::unbounded_channel();
+            let open = Arc::new(AtomicBool::new(false));
+            let task = tauri::async_runtime::spawn(run_connection(this.plugin_name.clone(), lua.weak(), request, callbacks, rx, open.clone()));
+            plugin.add_task(task);
+            plugin.add_message(format!("Opened WebSocket connection to '{}'.", uri));
+
+            return lua.create_userdata(LuaWebSocket { sender, open });
+        });
+    }
+}
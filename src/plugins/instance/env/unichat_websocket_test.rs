@@ -0,0 +1,136 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use futures::SinkExt as _;
+use futures::StreamExt as _;
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio_tungstenite::WebSocketStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest as _;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+
+use crate::plugins::instance::env::unichat_websocket::CLOSE_TIMEOUT;
+use crate::plugins::instance::env::unichat_websocket::WebSocketCallbacks;
+use crate::plugins::instance::env::unichat_websocket::run_connection;
+
+/// Accepts a single connection on a local port and hands it to `server`, returns the URL to connect to.
+async fn serve<F, Fut>(server: F) -> String
+where
+    F: FnOnce(WebSocketStream<tokio::net::TcpStream>) -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let (stream, _address) = listener.accept().await.unwrap();
+        let stream = tokio_tungstenite::accept_async(stream).await.unwrap();
+        server(stream).await;
+    });
+
+    return format!("ws://{}", address);
+}
+
+/// Callbacks record their calls in the `calls` global as `name:args`.
+fn callbacks(lua: &mlua::Lua, on_message: &str) -> WebSocketCallbacks {
+    lua.load("calls = {}").exec().unwrap();
+    let record = |name: &str| -> Option<mlua::Function> {
+        let code = format!("return function(a, b) table.insert(calls, '{}:' .. tostring(a) .. ',' .. tostring(b)) end", name);
+        return Some(lua.load(code).eval().unwrap());
+    };
+
+    return WebSocketCallbacks {
+        on_open: record("open"),
+        on_message: Some(lua.load(on_message).eval().unwrap()),
+        on_close: record("close"),
+        on_error: record("error")
+    };
+}
+
+fn calls(lua: &mlua::Lua) -> Vec<String> {
+    return lua.globals().get("calls").unwrap();
+}
+
+#[tokio::test]
+async fn test_close_from_plugin() {
+    let url = serve(|mut stream| async move {
+        // Reading the close frame answers it.
+        while let Some(Ok(_)) = stream.next().await {}
+    }).await;
+
+    let lua = mlua::Lua::new();
+    let callbacks = callbacks(&lua, "return function(data) table.insert(calls, 'message:' .. data) end");
+    let (sender, rx) = mpsc::unbounded_channel();
+    let open = Arc::new(AtomicBool::new(false));
+    sender.send(Message::Close(Some(CloseFrame { code: CloseCode::Normal, reason: "bye".into() }))).unwrap();
+
+    let request = url.into_client_request().unwrap();
+    let connection = run_connection(String::from("websocket-test"), lua.weak(), request, callbacks, rx, open.clone());
+    tokio::time::timeout(CLOSE_TIMEOUT, connection).await.unwrap();
+
+    assert!(!open.load(Ordering::SeqCst));
+    assert_eq!(calls(&lua), vec!["open:nil,nil", "close:1000,bye"]);
+}
+
+#[tokio::test]
+async fn test_failed_callback_closes_connection() {
+    let url = serve(|mut stream| async move {
+        stream.send(Message::text("first")).await.unwrap();
+        stream.send(Message::text("second")).await.unwrap();
+        while let Some(Ok(_)) = stream.next().await {}
+    }).await;
+
+    let lua = mlua::Lua::new();
+    let callbacks = callbacks(&lua, "return function(data) table.insert(calls, 'message:' .. data) error('boom') end");
+    let (_sender, rx) = mpsc::unbounded_channel();
+    let open = Arc::new(AtomicBool::new(false));
+
+    let request = url.into_client_request().unwrap();
+    let connection = run_connection(String::from("websocket-test"), lua.weak(), request, callbacks, rx, open.clone());
+    tokio::time::timeout(CLOSE_TIMEOUT, connection).await.unwrap();
+
+    // Messages received after the failure are dropped while the close handshake completes.
+    assert!(!open.load(Ordering::SeqCst));
+    assert_eq!(calls(&lua), vec!["open:nil,nil", "message:first", "close:nil,nil"]);
+}
+
+#[tokio::test]
+async fn test_close_timeout_is_not_extended_by_messages() {
+    let url = serve(|mut stream| async move {
+        // Never reads, so the close frame is not answered, and keeps the connection busy.
+        loop {
+            if stream.send(Message::text("noise")).await.is_err() {
+                break;
+            }
+
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    }).await;
+
+    let lua = mlua::Lua::new();
+    let callbacks = callbacks(&lua, "return function(data) table.insert(calls, 'message:' .. data) error('boom') end");
+    let (_sender, rx) = mpsc::unbounded_channel();
+    let open = Arc::new(AtomicBool::new(false));
+
+    let request = url.into_client_request().unwrap();
+    let connection = run_connection(String::from("websocket-test"), lua.weak(), request, callbacks, rx, open.clone());
+    tokio::time::timeout(CLOSE_TIMEOUT * 2, connection).await.unwrap();
+
+    assert!(!open.load(Ordering::SeqCst));
+    assert_eq!(calls(&lua), vec!["open:nil,nil", "message:noise", "close:nil,nil"]);
+}
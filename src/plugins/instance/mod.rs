@@ -21,46 +21,32 @@ use crate::plugins::LOADED_PLUGINS;
 use crate::plugins::LOADED_PLUGINS_LAZY_LOCK_KEY;
 use crate::plugins::PluginStatus;
 use crate::plugins::UniChatPlugin;
+use crate::plugins::dependencies::UNICHAT_DEPENDENCY;
+use crate::plugins::dependencies::parse_dependencies;
+use crate::plugins::is_plugin_enabled;
+use crate::plugins::limits;
+use crate::plugins::limits::ExecutionLimits;
 use crate::plugins::manifest::PluginManifestYAML;
-use crate::plugins::runtime;
+use crate::scraper;
 use crate::utils::get_current_timestamp;
 use crate::utils::semver;
 use crate::widgets;
 
 mod env;
 
-fn parse_dependencies(raw_dependencies: &Vec<String>) -> Result<Vec<(String, semver::VersionRange)>, Error> {
-    let mut dependencies: Vec<(String, semver::VersionRange)> = Vec::new();
-
-    for dep in raw_dependencies {
-        let parts: Vec<&str> = dep.splitn(2, '@').collect();
-        if parts.len() != 2 {
-            return Err(anyhow!("Invalid dependency format: '{}'. Expected format is 'name@version_req'", dep));
-        }
-
-        let name = parts[0].trim().to_string();
-        let version = parts[1].trim();
-        let version_req = semver::VersionRange::parse(version)?;
-
-        dependencies.push((name, version_req));
-    }
-
-    return Ok(dependencies);
-}
-
 /* ========================================================================== */
 
 fn start(plugin: &Arc<UniChatPlugin>) -> Result<(), Error> {
-    let lua = runtime::get()?;
+    let lua = plugin.get_lua();
     let plugin_env = plugin.get_plugin_env()?;
-    env::load_env(&plugin.name, &plugin.version, plugin_env.clone())?;
+    env::load_env(&lua, &plugin.name, &plugin.version, plugin_env.clone())?;
 
     /* ====================================================================== */
 
     let start_ms = get_current_timestamp()?;
     log::info!("Executing plugin entrypoint for plugin: {} v{}", plugin.name, plugin.version);
     let entrypoint_code = fs::read_to_string(plugin.get_entrypoint_path())?;
-    lua.load(&entrypoint_code).set_environment(plugin_env.clone()).exec()?;
+    limits::call_plugin(&plugin.name, ExecutionLimits::ENTRYPOINT, || lua.load(&entrypoint_code).set_environment(plugin_env.clone()).exec())?;
     let end_ms = get_current_timestamp()?;
     log::info!("Plugin '{}' initialized in {} ms", plugin.name, end_ms - start_ms);
     plugin.add_message(format!("Initialization finished in {}ms", end_ms - start_ms));
@@ -70,7 +56,8 @@ fn start(plugin: &Arc<UniChatPlugin>) -> Result<(), Error> {
 
 /* ========================================================================== */
 
-pub fn create(plugin_path: &Path, manifest: &PluginManifestYAML) -> Result<(), Error> {
+/// Creates and starts a plugin, `dependency_errors` comes from the dependency resolution and marks it as invalid.
+pub fn create(plugin_path: &Path, manifest: &PluginManifestYAML, dependency_errors: &[String]) -> Result<(), Error> {
     let parsed_dependencies = parse_dependencies(&manifest.dependencies)?;
     let plugin = Arc::new(UniChatPlugin::new(plugin_path, manifest, parsed_dependencies)?);
     let mut is_valid = true;
@@ -81,7 +68,7 @@ pub fn create(plugin_path: &Path, manifest: &PluginManifestYAML) -> Result<(), E
     }
 
     for (key, version_req) in plugin.dependencies.iter() {
-        if key == "unichat" {
+        if key == UNICHAT_DEPENDENCY {
             let unichat_version = semver::Version::parse(UNICHAT_VERSION)?;
             if !version_req.matches(&unichat_version) {
                 plugin.add_message(format!("Required {} version '{}' does not satisfy the current version '{}'", UNICHAT_DISPLAY_NAME, version_req, unichat_version));
@@ -90,6 +77,11 @@ pub fn create(plugin_path: &Path, manifest: &PluginManifestYAML) -> Result<(), E
         }
     }
 
+    for message in dependency_errors {
+        plugin.add_message(message.clone());
+        is_valid = false;
+    }
+
     {
         let mut loaded_plugins = LOADED_PLUGINS.write().map_err(|_| anyhow!("{} lock poisoned", LOADED_PLUGINS_LAZY_LOCK_KEY))?;
         if loaded_plugins.contains_key(&plugin.name) {
@@ -99,6 +91,13 @@ pub fn create(plugin_path: &Path, manifest: &PluginManifestYAML) -> Result<(), E
         loaded_plugins.insert(plugin.name.clone(), plugin.clone());
     }
 
+    if !is_plugin_enabled(&plugin.name) {
+        plugin.set_status(PluginStatus::Disabled);
+        plugin.add_message("Plugin is disabled.");
+        log::info!("Plugin '{}' is disabled, skipping start", plugin.name);
+        return Ok(());
+    }
+
     if !is_valid {
         plugin.set_status(PluginStatus::Invalid);
         return Err(anyhow!("Plugin '{}' initialization failed due to previous errors", plugin.name));
@@ -107,6 +106,11 @@ pub fn create(plugin_path: &Path, manifest: &PluginManifestYAML) -> Result<(), E
     plugin.set_status(PluginStatus::Loaded);
     log::info!("Plugin '{}' loaded", plugin.name);
 
+    let permissions = plugin.permissions.to_strings();
+    if !permissions.is_empty() {
+        plugin.add_message(format!("Granted permissions: {}", permissions.join(", ")));
+    }
+
     if let Err(e) = widgets::add_plugin_widgets(&plugin) {
         plugin.add_message(format!("An error occurred while loading plugin widgets: {:?}", e));
         log::error!("Failed to load widgets for plugin '{}': {:?}", plugin.name, e);
@@ -115,6 +119,7 @@ pub fn create(plugin_path: &Path, manifest: &PluginManifestYAML) -> Result<(), E
     if let Err(e) = start(&plugin) {
         plugin.add_message(format!("An error occurred on start plugin: {:?}", e));
         plugin.set_status(PluginStatus::Error);
+        env::unload_env(&plugin);
         return Err(anyhow!("Failed to create LUA environment for plugin '{}': {:?}", plugin.name, e));
     }
 
@@ -123,3 +128,26 @@ pub fn create(plugin_path: &Path, manifest: &PluginManifestYAML) -> Result<(), E
 
     return Ok(());
 }
+
+/// Releases everything the plugin registered while running and removes it from the loaded plugins.
+pub fn destroy(plugin: &Arc<UniChatPlugin>) -> Result<(), Error> {
+    env::unload_env(plugin);
+
+    for scraper_id in plugin.take_scrapers() {
+        if let Err(e) = scraper::unregister_scraper(&scraper_id) {
+            log::error!("Failed to unregister scraper '{}' of plugin '{}': {:?}", scraper_id, plugin.name, e);
+        }
+    }
+
+    if let Err(e) = widgets::remove_plugin_widgets(&plugin.name) {
+        log::error!("Failed to remove widgets of plugin '{}': {:?}", plugin.name, e);
+    }
+
+    plugin.clear_settings_listeners();
+    plugin.clear_cached_loaded_modules();
+
+    let mut loaded_plugins = LOADED_PLUGINS.write().map_err(|_| anyhow!("{} lock poisoned", LOADED_PLUGINS_LAZY_LOCK_KEY))?;
+    loaded_plugins.remove(&plugin.name);
+
+    return Ok(());
+}
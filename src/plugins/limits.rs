@@ -0,0 +1,167 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::time::Duration;
+use std::time::Instant;
+
+use anyhow::Error;
+
+use crate::plugins::get_plugin;
+use crate::plugins::set_plugin_enabled;
+use crate::utils::render_emitter;
+
+/// Memory cap of each plugin LUA state, allocations above it fail with a memory error.
+pub const MEMORY_LIMIT_BYTES: usize = 64 * 1024 * 1024;
+/// Plugins exceeding their limits this many times are disabled.
+pub const MAX_LIMIT_FAILURES: u32 = 3;
+const HOOK_INSTRUCTION_INTERVAL: u32 = 1000;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ExecutionLimits {
+    pub time: Duration,
+    pub instructions: u64
+}
+
+impl ExecutionLimits {
+    /// Event listeners, timers and any other callback invoked by UniChat.
+    pub const CALLBACK: Self = Self { time: Duration::from_secs(2), instructions: 50_000_000 };
+    /// Event interceptors run before every event is broadcast, so they get a much shorter budget.
+    pub const INTERCEPTOR: Self = Self { time: Duration::from_millis(100), instructions: 5_000_000 };
+    /// The plugin `main.lua`, it usually does more work than a single callback.
+    pub const ENTRYPOINT: Self = Self { time: Duration::from_secs(10), instructions: 200_000_000 };
+}
+
+#[derive(Debug)]
+pub struct LimitExceeded(String);
+
+impl Display for LimitExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "{}", self.0);
+    }
+}
+
+impl std::error::Error for LimitExceeded {}
+
+/* ============================================================================================== */
+
+struct Budget {
+    deadline: Instant,
+    instructions_left: u64,
+    limits: ExecutionLimits
+}
+
+thread_local! {
+    // A stack since a callback may call into LUA again (e.g. a shared module calling another plugin).
+    static BUDGETS: RefCell<Vec<Budget>> = const { RefCell::new(Vec::new()) };
+}
+
+fn check_budget(event: mlua::debug::DebugEvent) -> Result<mlua::VmState, mlua::Error> {
+    return BUDGETS.with_borrow_mut(|budgets| {
+        let Some(budget) = budgets.last_mut() else {
+            return Ok(mlua::VmState::Continue);
+        };
+
+        if event == mlua::debug::DebugEvent::Count {
+            budget.instructions_left = budget.instructions_left.saturating_sub(HOOK_INSTRUCTION_INTERVAL as u64);
+        }
+
+        if budget.instructions_left == 0 {
+            let message = format!("Instruction limit of {} exceeded", budget.limits.instructions);
+            return Err(mlua::Error::external(LimitExceeded(message)));
+        } else if Instant::now() > budget.deadline {
+            let message = format!("Execution time limit of {}ms exceeded", budget.limits.time.as_millis());
+            return Err(mlua::Error::external(LimitExceeded(message)));
+        }
+
+        return Ok(mlua::VmState::Continue);
+    });
+}
+
+/// Sets the memory limit and the hook that enforces the budgets of [`guarded`].
+/// The hook also runs on calls, otherwise a `pcall` inside a loop would swallow the limit error forever.
+pub fn install(lua: &mlua::Lua) -> Result<(), Error> {
+    lua.set_memory_limit(MEMORY_LIMIT_BYTES)?;
+    let triggers = mlua::HookTriggers::new().on_calls().every_nth_instruction(HOOK_INSTRUCTION_INTERVAL);
+    lua.set_global_hook(triggers, |_lua, debug| {
+        return check_budget(debug.event());
+    })?;
+
+    return Ok(());
+}
+
+struct BudgetGuard;
+
+impl Drop for BudgetGuard {
+    fn drop(&mut self) {
+        BUDGETS.with_borrow_mut(|budgets| budgets.pop());
+    }
+}
+
+/// Runs LUA code within the limits, nested calls never extend the deadline of the outer call.
+pub fn guarded<R>(limits: ExecutionLimits, f: impl FnOnce() -> mlua::Result<R>) -> mlua::Result<R> {
+    BUDGETS.with_borrow_mut(|budgets| {
+        let mut deadline = Instant::now() + limits.time;
+        if let Some(outer) = budgets.last() {
+            deadline = deadline.min(outer.deadline);
+        }
+
+        budgets.push(Budget { deadline, instructions_left: limits.instructions, limits });
+    });
+
+    let _guard = BudgetGuard;
+    return f();
+}
+
+pub fn is_limit_error(err: &mlua::Error) -> bool {
+    if err.downcast_ref::<LimitExceeded>().is_some() {
+        return true;
+    }
+
+    return err.chain().any(|e| matches!(e.downcast_ref::<mlua::Error>(), Some(mlua::Error::MemoryError(_))));
+}
+
+/* ============================================================================================== */
+
+fn record_failure(plugin_name: &str, err: &mlua::Error) {
+    let Ok(plugin) = get_plugin(plugin_name) else {
+        return;
+    };
+
+    let failures = plugin.add_limit_failure();
+    log::error!(target: &format!("plugin:{}", plugin_name), "Plugin exceeded its execution limits ({}/{}): {}", failures, MAX_LIMIT_FAILURES, err);
+    plugin.add_message(format!("Execution limits exceeded ({}/{}): {}", failures, MAX_LIMIT_FAILURES, err));
+
+    if failures == MAX_LIMIT_FAILURES {
+        let plugin_name = plugin_name.to_string();
+        // Disabling reloads the plugin, which aborts the task this callback may be running on.
+        tauri::async_runtime::spawn(async move {
+            log::warn!("Disabling plugin '{}' after repeatedly exceeding its execution limits", plugin_name);
+            if let Err(err) = set_plugin_enabled(&plugin_name, false) {
+                log::error!("Failed to disable plugin '{}': {:?}", plugin_name, err);
+            }
+
+            render_emitter::emit_notification(&plugin_name, "Plugin was disabled after repeatedly exceeding its execution limits.");
+        });
+    }
+}
+
+/// Runs a plugin callback within the limits, counting violations against the plugin.
+pub fn call_plugin<R>(plugin_name: &str, limits: ExecutionLimits, f: impl FnOnce() -> mlua::Result<R>) -> mlua::Result<R> {
+    let result = guarded(limits, f);
+    if let Err(err) = &result {
+        if is_limit_error(err) {
+            record_failure(plugin_name, err);
+        }
+    }
+
+    return result;
+}
@@ -0,0 +1,56 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::time::Duration;
+
+use crate::plugins::limits::guarded;
+use crate::plugins::limits::install;
+use crate::plugins::limits::is_limit_error;
+use crate::plugins::limits::ExecutionLimits;
+
+fn new_lua() -> mlua::Lua {
+    let lua = mlua::Lua::new();
+    install(&lua).unwrap();
+    return lua;
+}
+
+#[test]
+fn test_infinite_loop_is_terminated() {
+    let lua = new_lua();
+    let limits = ExecutionLimits { time: Duration::from_millis(50), instructions: u64::MAX };
+
+    let err = guarded(limits, || lua.load("while true do end").exec()).unwrap_err();
+    assert!(is_limit_error(&err));
+    assert!(err.to_string().contains("Execution time limit of 50ms exceeded"));
+
+    // The budget is released, code outside guarded calls is not limited.
+    assert!(lua.load("for i = 1, 100000 do end").exec().is_ok());
+}
+
+#[test]
+fn test_instruction_limit() {
+    let lua = new_lua();
+    let limits = ExecutionLimits { time: Duration::from_secs(60), instructions: 10_000 };
+
+    assert!(guarded(limits, || lua.load("local x = 0 for i = 1, 10 do x = x + i end").exec()).is_ok());
+
+    let err = guarded(limits, || lua.load("local x = 0 for i = 1, 1000000 do x = x + i end").exec()).unwrap_err();
+    assert!(is_limit_error(&err));
+}
+
+#[test]
+fn test_limit_errors_survive_pcall() {
+    let lua = new_lua();
+    let limits = ExecutionLimits { time: Duration::from_millis(50), instructions: u64::MAX };
+
+    let err = guarded(limits, || lua.load("while true do pcall(function() while true do end end) end").exec()).unwrap_err();
+    assert!(is_limit_error(&err));
+    assert!(!is_limit_error(&mlua::Error::runtime("plain error")));
+}
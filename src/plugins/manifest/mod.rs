@@ -32,7 +32,9 @@ pub struct PluginManifestYAML {
     pub author: Option<String>,
     pub license: Option<String>,
     pub homepage: Option<String>,
-    pub dependencies: Vec<String>
+    pub dependencies: Vec<String>,
+    #[serde(default)]
+    pub permissions: Vec<String>
 }
 
 pub fn load_manifest(plugin_path: &Path) -> Result<PluginManifestYAML, Error> {
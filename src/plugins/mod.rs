@@ -15,18 +15,47 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::LazyLock;
 use std::sync::RwLock;
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 
 use anyhow::anyhow;
 use anyhow::Error;
-
+use indexmap::IndexMap;
+use mlua::LuaSerdeExt as _;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::plugins::dependencies::DependencyNode;
+use crate::plugins::dependencies::UNICHAT_DEPENDENCY;
+use crate::plugins::limits::ExecutionLimits;
 use crate::plugins::manifest::PluginManifestYAML;
 use crate::plugins::manifest::load_manifest;
+use crate::plugins::permissions::PluginPermission;
+use crate::plugins::permissions::PluginPermissions;
 use crate::utils::properties;
 use crate::utils::properties::AppPaths;
 use crate::utils::semver;
-
+use crate::utils::settings;
+use crate::utils::settings::SETTINGS_ACKNOWLEDGED_PLUGIN_PERMISSIONS_KEY;
+use crate::utils::settings::SETTINGS_DISABLED_PLUGINS_KEY;
+
+#[cfg(test)] mod archive_test;
+pub mod archive;
+#[cfg(test)] mod dependencies_test;
+mod dependencies;
 mod instance;
+#[cfg(test)] mod limits_test;
+mod limits;
 mod manifest;
+#[cfg(test)] mod permissions_test;
+mod permissions;
+#[cfg(test)] mod plugin_settings_test;
+mod plugin_settings;
+#[cfg(test)] mod routes_test;
+pub mod routes;
+#[cfg(test)] mod rpc_test;
+pub mod rpc;
 mod runtime;
 
 const LOADED_PLUGINS_LAZY_LOCK_KEY: &str = "Plugins::LOADED_PLUGINS";
@@ -34,6 +63,10 @@ static LOADED_PLUGINS: LazyLock<RwLock<HashMap<String, Arc<UniChatPlugin>>>> = L
 
 /* ============================================================================================== */
 
+pub fn is_valid_plugin_name(name: &str) -> bool {
+    return !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+}
+
 pub fn get_plugins() -> Result<Vec<Arc<UniChatPlugin>>, Error> {
     let envs = LOADED_PLUGINS.read().map_err(|_| anyhow!("{} lock poisoned", LOADED_PLUGINS_LAZY_LOCK_KEY))?;
 
@@ -51,6 +84,80 @@ pub fn get_plugin(plugin_name: &str) -> Result<Arc<UniChatPlugin>, Error> {
     return Ok(manifest.clone());
 }
 
+pub fn is_plugin_enabled(plugin_name: &str) -> bool {
+    let disabled_plugins: Vec<String> = settings::get_item(SETTINGS_DISABLED_PLUGINS_KEY).unwrap_or_default();
+    return !disabled_plugins.iter().any(|name| name == plugin_name);
+}
+
+/// Persists the flag and reloads the plugin, a disabled plugin stays listed but is not started.
+pub fn set_plugin_enabled(plugin_name: &str, enabled: bool) -> Result<Arc<UniChatPlugin>, Error> {
+    let mut disabled_plugins: Vec<String> = settings::get_item(SETTINGS_DISABLED_PLUGINS_KEY).unwrap_or_default();
+    disabled_plugins.retain(|name| name != plugin_name);
+    if !enabled {
+        disabled_plugins.push(plugin_name.to_string());
+    }
+
+    settings::set_item(SETTINGS_DISABLED_PLUGINS_KEY, &disabled_plugins)?;
+    return reload_plugin(plugin_name);
+}
+
+/// Granted permissions not yet shown to the user, they are listed again when the manifest asks for new ones.
+pub fn get_pending_permissions(plugin: &UniChatPlugin) -> Vec<String> {
+    let acknowledged: HashMap<String, Vec<String>> = settings::get_item(SETTINGS_ACKNOWLEDGED_PLUGIN_PERMISSIONS_KEY).unwrap_or_default();
+    let acknowledged = acknowledged.get(&plugin.name).cloned().unwrap_or_default();
+
+    return plugin.permissions.to_strings().into_iter().filter(|p| !acknowledged.contains(p)).collect();
+}
+
+pub fn acknowledge_permissions(plugin_name: &str) -> Result<(), Error> {
+    let plugin = get_plugin(plugin_name)?;
+    let mut acknowledged: HashMap<String, Vec<String>> = settings::get_item(SETTINGS_ACKNOWLEDGED_PLUGIN_PERMISSIONS_KEY).unwrap_or_default();
+    acknowledged.insert(plugin.name.clone(), plugin.permissions.to_strings());
+
+    return settings::set_item(SETTINGS_ACKNOWLEDGED_PLUGIN_PERMISSIONS_KEY, &acknowledged);
+}
+
+/// Stored setting values as edited by the user, without defaults.
+pub fn get_plugin_settings(plugin_name: &str) -> Result<Map<String, Value>, Error> {
+    get_plugin(plugin_name)?;
+    return Ok(plugin_settings::read_state(plugin_name));
+}
+
+/// Persists the changed settings and notifies the plugin about every value that changed.
+pub fn set_plugin_settings(plugin_name: &str, changes: &Map<String, Value>) -> Result<Map<String, Value>, Error> {
+    let plugin = get_plugin(plugin_name)?;
+    let state = plugin_settings::read_state(plugin_name);
+    let merged = plugin_settings::merge(&plugin.setting_fields, &state, changes)?;
+    plugin_settings::write_state(plugin_name, &merged)?;
+
+    let old_values = plugin_settings::resolve(&plugin.setting_fields, &state);
+    let new_values = plugin_settings::resolve(&plugin.setting_fields, &merged);
+    let callbacks: Vec<mlua::Function> = plugin.settings_listeners.read().map(|l| l.clone()).unwrap_or_default();
+    if callbacks.is_empty() || plugin.get_status() != PluginStatus::Active {
+        return Ok(merged);
+    }
+
+    let lua = plugin.get_lua();
+    for (key, value) in new_values.iter() {
+        let old_value = old_values.get(key).unwrap_or(&Value::Null);
+        if old_value == value {
+            continue;
+        }
+
+        let lua_value = lua.to_value(value)?;
+        let lua_old_value = lua.to_value(old_value)?;
+        for callback in callbacks.iter() {
+            let result = limits::call_plugin(plugin_name, ExecutionLimits::CALLBACK, || callback.call::<()>((key.clone(), lua_value.clone(), lua_old_value.clone())));
+            if let Err(err) = result {
+                log::error!(target: &format!("plugin:{}", plugin_name), "An error occurred on UniChatAPI settings change callback: {}", err);
+                plugin.add_message(format!("Settings change callback failed for '{}': {}", key, err));
+            }
+        }
+    }
+
+    return Ok(merged);
+}
+
 /* ============================================================================================== */
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -60,6 +167,7 @@ pub enum PluginStatus {
     Invalid,
     Active,
     Error,
+    Disabled,
 }
 
 /* ============================================================================================== */
@@ -72,20 +180,38 @@ pub struct UniChatPlugin {
     pub license: Option<String>,
     pub homepage: Option<String>,
     pub dependencies: Vec<(String, semver::VersionRange)>,
+    pub permissions: PluginPermissions,
+    pub setting_fields: IndexMap<String, Value>,
 
     status: RwLock<PluginStatus>,
     messages: RwLock<Vec<String>>,
+    limit_failures: AtomicU32,
 
     plugin_path: PathBuf,
+    lua: mlua::Lua,
     plugin_env: mlua::Table,
     loaded_modules_cache: RwLock<HashMap<String, mlua::Value>>,
+
+    tasks: RwLock<Vec<tauri::async_runtime::JoinHandle<()>>>,
+    timers: RwLock<HashMap<u64, tauri::async_runtime::JoinHandle<()>>>,
+    next_timer_id: AtomicU64,
+    exposed_modules: RwLock<Vec<String>>,
+    scrapers: RwLock<Vec<String>>,
+    interceptors: RwLock<Vec<u64>>,
+    rpc_methods: RwLock<HashMap<String, mlua::Function>>,
+    http_routes: RwLock<Vec<(String, String, mlua::Function)>>,
+    settings_listeners: RwLock<Vec<mlua::Function>>,
 }
 
 impl UniChatPlugin {
     pub(in crate::plugins) fn new(plugin_path: &Path, manifest: &PluginManifestYAML, dependencies: Vec<(String, semver::VersionRange)>) -> Result<Self, Error> {
         let version = semver::Version::parse(&manifest.version)?;
 
-        let lua = runtime::get()?;
+        let (permissions, mut warnings) = PluginPermissions::from_manifest(&manifest.permissions);
+        let (setting_fields, settings_warnings) = plugin_settings::load_fields(plugin_path);
+        warnings.extend(settings_warnings);
+
+        let lua = runtime::create()?;
         let env = lua.create_table()?;
 
         return Ok(Self {
@@ -96,13 +222,27 @@ impl UniChatPlugin {
             license: manifest.license.clone(),
             homepage: manifest.homepage.clone(),
             dependencies: dependencies,
+            permissions: permissions,
+            setting_fields: setting_fields,
 
             status: RwLock::new(PluginStatus::Loaded),
-            messages: RwLock::new(Vec::new()),
+            messages: RwLock::new(warnings),
+            limit_failures: AtomicU32::new(0),
 
             plugin_path: plugin_path.to_path_buf(),
+            lua: lua,
             plugin_env: env,
-            loaded_modules_cache: RwLock::new(HashMap::new())
+            loaded_modules_cache: RwLock::new(HashMap::new()),
+
+            tasks: RwLock::new(Vec::new()),
+            timers: RwLock::new(HashMap::new()),
+            next_timer_id: AtomicU64::new(1),
+            exposed_modules: RwLock::new(Vec::new()),
+            scrapers: RwLock::new(Vec::new()),
+            interceptors: RwLock::new(Vec::new()),
+            rpc_methods: RwLock::new(HashMap::new()),
+            http_routes: RwLock::new(Vec::new()),
+            settings_listeners: RwLock::new(Vec::new())
         });
     }
 
@@ -133,6 +273,32 @@ impl UniChatPlugin {
 
     /* ====================================================================== */
 
+    /// How many times the plugin exceeded its execution limits since it was loaded.
+    pub fn get_limit_failures(&self) -> u32 {
+        return self.limit_failures.load(Ordering::SeqCst);
+    }
+
+    pub(in crate::plugins) fn add_limit_failure(&self) -> u32 {
+        return self.limit_failures.fetch_add(1, Ordering::SeqCst) + 1;
+    }
+
+    /* ====================================================================== */
+
+    pub fn require_permission(&self, permission: &PluginPermission) -> Result<(), Error> {
+        if !self.permissions.has(permission) {
+            return Err(anyhow!("Plugin '{}' requires the '{}' permission", self.name, permission));
+        }
+
+        return Ok(());
+    }
+
+    /* ====================================================================== */
+
+    /// The plugin own LUA state, dropped together with the plugin.
+    pub(in crate::plugins) fn get_lua(&self) -> mlua::Lua {
+        return self.lua.clone();
+    }
+
     pub(in crate::plugins) fn get_plugin_env(&self) -> Result<mlua::Table, Error> {
         return Ok(self.plugin_env.clone());
     }
@@ -156,6 +322,142 @@ impl UniChatPlugin {
         return Ok(cached_module.clone());
     }
 
+    pub(in crate::plugins) fn clear_cached_loaded_modules(&self) {
+        let mut cache = self.loaded_modules_cache.write().unwrap();
+        cache.clear();
+    }
+
+    /* ====================================================================== */
+
+    pub(in crate::plugins) fn add_task(&self, task: tauri::async_runtime::JoinHandle<()>) {
+        let mut tasks = self.tasks.write().unwrap();
+        // WebSocket connections add a task each, drop the ones that already ended.
+        tasks.retain(|t| !t.inner().is_finished());
+        tasks.push(task);
+    }
+
+    pub(in crate::plugins) fn take_tasks(&self) -> Vec<tauri::async_runtime::JoinHandle<()>> {
+        let mut tasks = self.tasks.write().unwrap();
+        return tasks.drain(..).collect();
+    }
+
+    pub(in crate::plugins) fn next_timer_id(&self) -> u64 {
+        return self.next_timer_id.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub(in crate::plugins) fn add_timer(&self, timer_id: u64, task: tauri::async_runtime::JoinHandle<()>) {
+        let mut timers = self.timers.write().unwrap();
+        timers.retain(|_, t| !t.inner().is_finished());
+        timers.insert(timer_id, task);
+    }
+
+    pub(in crate::plugins) fn remove_timer(&self, timer_id: u64) -> Option<tauri::async_runtime::JoinHandle<()>> {
+        let mut timers = self.timers.write().unwrap();
+        return timers.remove(&timer_id);
+    }
+
+    pub(in crate::plugins) fn take_timers(&self) -> Vec<tauri::async_runtime::JoinHandle<()>> {
+        let mut timers = self.timers.write().unwrap();
+        return timers.drain().map(|(_, task)| task).collect();
+    }
+
+    pub(in crate::plugins) fn add_exposed_module(&self, module_name: &str) {
+        let mut exposed_modules = self.exposed_modules.write().unwrap();
+        exposed_modules.push(module_name.to_string());
+    }
+
+    pub(in crate::plugins) fn take_exposed_modules(&self) -> Vec<String> {
+        let mut exposed_modules = self.exposed_modules.write().unwrap();
+        return exposed_modules.drain(..).collect();
+    }
+
+    pub(in crate::plugins) fn add_scraper(&self, scraper_id: &str) {
+        let mut scrapers = self.scrapers.write().unwrap();
+        scrapers.push(scraper_id.to_string());
+    }
+
+    pub(in crate::plugins) fn take_scrapers(&self) -> Vec<String> {
+        let mut scrapers = self.scrapers.write().unwrap();
+        return scrapers.drain(..).collect();
+    }
+
+    pub(in crate::plugins) fn add_interceptor(&self, interceptor_id: u64) {
+        let mut interceptors = self.interceptors.write().unwrap();
+        interceptors.push(interceptor_id);
+    }
+
+    pub(in crate::plugins) fn remove_interceptor(&self, interceptor_id: u64) -> bool {
+        let mut interceptors = self.interceptors.write().unwrap();
+        let count = interceptors.len();
+        interceptors.retain(|id| *id != interceptor_id);
+
+        return interceptors.len() != count;
+    }
+
+    pub(in crate::plugins) fn take_interceptors(&self) -> Vec<u64> {
+        let mut interceptors = self.interceptors.write().unwrap();
+        return interceptors.drain(..).collect();
+    }
+
+    pub(in crate::plugins) fn add_rpc_method(&self, method_name: &str, callback: mlua::Function) -> Result<(), Error> {
+        let mut rpc_methods = self.rpc_methods.write().unwrap();
+        if rpc_methods.contains_key(method_name) {
+            return Err(anyhow!("RPC method '{}' is already registered", method_name));
+        }
+
+        rpc_methods.insert(method_name.to_string(), callback);
+        return Ok(());
+    }
+
+    pub(in crate::plugins) fn get_rpc_method(&self, method_name: &str) -> Option<mlua::Function> {
+        let rpc_methods = self.rpc_methods.read().unwrap();
+        return rpc_methods.get(method_name).cloned();
+    }
+
+    pub(in crate::plugins) fn clear_rpc_methods(&self) {
+        let mut rpc_methods = self.rpc_methods.write().unwrap();
+        rpc_methods.clear();
+    }
+
+    pub(in crate::plugins) fn add_http_route(&self, method: &str, path: &str, handler: mlua::Function) -> Result<(), Error> {
+        let mut http_routes = self.http_routes.write().unwrap();
+        if http_routes.iter().any(|(m, p, _handler)| m == method && p == path) {
+            return Err(anyhow!("HTTP route '{} {}' is already registered", method, path));
+        }
+
+        http_routes.push((method.to_string(), path.to_string(), handler));
+        return Ok(());
+    }
+
+    /// Routes are matched in registration order.
+    pub(in crate::plugins) fn find_http_route(&self, method: &str, path: &str) -> Option<mlua::Function> {
+        let http_routes = self.http_routes.read().unwrap();
+        let route = http_routes.iter().find(|(m, p, _handler)| routes::route_matches(m, p, method, path));
+        return route.map(|(_method, _path, handler)| handler.clone());
+    }
+
+    pub(in crate::plugins) fn clear_http_routes(&self) {
+        let mut http_routes = self.http_routes.write().unwrap();
+        http_routes.clear();
+    }
+
+    pub(in crate::plugins) fn add_settings_listener(&self, callback: mlua::Function) {
+        let mut settings_listeners = self.settings_listeners.write().unwrap();
+        settings_listeners.push(callback);
+    }
+
+    pub(in crate::plugins) fn clear_settings_listeners(&self) {
+        let mut settings_listeners = self.settings_listeners.write().unwrap();
+        settings_listeners.clear();
+    }
+
+    /* ====================================================================== */
+
+    /// Current setting values with the field defaults applied.
+    pub fn get_settings(&self) -> Map<String, Value> {
+        return plugin_settings::resolve(&self.setting_fields, &plugin_settings::read_state(&self.name));
+    }
+
     /* ====================================================================== */
 
     pub fn get_plugin_path(&self) -> PathBuf {
@@ -202,8 +504,10 @@ impl UniChatPlugin {
 
 /* ================================================================================================================== */
 
+/// Plugins get their own LUA state when loaded, this only checks the sandbox can be created at all.
 pub fn init() -> Result<(), Error> {
-    return runtime::new();
+    runtime::create()?;
+    return Ok(());
 }
 
 fn load_plugins_from_disk(plugins_path: PathBuf) -> Result<Vec<(PathBuf, PluginManifestYAML)>, Error> {
@@ -220,7 +524,7 @@ fn load_plugins_from_disk(plugins_path: PathBuf) -> Result<Vec<(PathBuf, PluginM
                 let plugin_folder = plugin_path.file_name().unwrap_or_default().to_string_lossy().to_string();
                 if plugin_folder.starts_with(".") {
                     continue;
-                } else if plugin_folder.chars().any(|c| !c.is_ascii_alphanumeric() && c != '_' && c != '-') {
+                } else if !is_valid_plugin_name(&plugin_folder) {
                     log::warn!("Skipping plugin with invalid name '{}' from {:?}", plugin_folder, plugin_path);
                     continue;
                 }
@@ -261,12 +565,154 @@ pub fn load_plugins() -> Result<(), Error> {
     let user_plugins_manifests = load_plugins_from_disk(user_plugins_dir)?;
     loaded_manifests.extend(user_plugins_manifests);
 
-    for (plugin_path, manifest) in loaded_manifests.iter_mut() {
+    let mut manifests: HashMap<String, (PathBuf, PluginManifestYAML)> = HashMap::new();
+    let mut nodes: Vec<DependencyNode> = Vec::new();
+    for (plugin_path, manifest) in loaded_manifests {
+        if manifests.contains_key(&manifest.name) {
+            log::error!("Skipping plugin '{}' from {:?}, a plugin with the same name was already found", manifest.name, plugin_path);
+            continue;
+        }
+
+        nodes.push(DependencyNode {
+            name: manifest.name.clone(),
+            version: semver::Version::parse(&manifest.version)?,
+            dependencies: dependencies::parse_dependencies(&manifest.dependencies).ok(),
+            disabled: !is_plugin_enabled(&manifest.name)
+        });
+        manifests.insert(manifest.name.clone(), (plugin_path, manifest));
+    }
+
+    let plan = dependencies::resolve_load_order(&nodes);
+    for name in plan.order.iter() {
+        let Some((plugin_path, manifest)) = manifests.get(name) else {
+            continue;
+        };
+
+        // The plan only knows the manifests, a dependency whose entrypoint failed is caught here like on reload.
+        let mut dependency_errors = plan.errors.get(name).cloned().unwrap_or_default();
+        if dependency_errors.is_empty() {
+            dependency_errors = loaded_dependency_errors(manifest);
+        }
+
         log::info!("Loading plugin: {} v{}", manifest.name, manifest.version);
-        if let Err(e) = instance::create(plugin_path, manifest) {
+        if let Err(e) = instance::create(plugin_path, manifest, &dependency_errors) {
             log::error!("Failed to load user plugin: {:?}", e);
         }
     }
 
     return Ok(());
 }
+
+/// Checks the plugin dependencies against the plugins currently loaded, dependencies must be `Active`.
+fn loaded_dependency_errors(manifest: &PluginManifestYAML) -> Vec<String> {
+    let mut errors: Vec<String> = Vec::new();
+    let Ok(dependencies) = dependencies::parse_dependencies(&manifest.dependencies) else {
+        return errors;
+    };
+
+    for (dependency, version_req) in dependencies {
+        if dependency == UNICHAT_DEPENDENCY {
+            continue;
+        }
+
+        match get_plugin(&dependency) {
+            Err(_) => errors.push(format!("Required plugin '{}' ({}) is not installed", dependency, version_req)),
+            Ok(plugin) if !version_req.matches(&plugin.version) => {
+                errors.push(format!("Required plugin '{}' version '{}' does not satisfy the installed version '{}'", dependency, version_req, plugin.version));
+            }
+            Ok(plugin) if plugin.get_status() != PluginStatus::Active => {
+                errors.push(format!("Required plugin '{}' is not active ({:?})", dependency, plugin.get_status()));
+            }
+            Ok(_) => {}
+        }
+    }
+
+    return errors;
+}
+
+/// Finds the folder of a plugin on disk, system plugins win over user plugins with the same name like on startup.
+fn find_plugin_path(plugin_name: &str) -> Result<PathBuf, Error> {
+    if !is_valid_plugin_name(plugin_name) {
+        return Err(anyhow!("Invalid plugin name '{}'", plugin_name));
+    }
+
+    let system_plugin_path = properties::get_app_path(AppPaths::UniChatSystemPlugins).join(plugin_name);
+    if system_plugin_path.join("manifest.yaml").is_file() {
+        return Ok(system_plugin_path);
+    }
+
+    let user_plugin_path = properties::get_app_path(AppPaths::UniChatUserPlugins).join(plugin_name);
+    if user_plugin_path.join("manifest.yaml").is_file() {
+        return Ok(user_plugin_path);
+    }
+
+    return Err(anyhow!("Plugin '{}' was not found on disk", plugin_name));
+}
+
+/// Loaded plugins depending on `plugin_name`, directly or through another plugin, each one after its dependencies.
+fn get_dependents(plugin_name: &str) -> Vec<Arc<UniChatPlugin>> {
+    let plugins = get_plugins().unwrap_or_default();
+    let nodes: Vec<DependencyNode> = plugins.iter().map(|plugin| DependencyNode {
+        name: plugin.name.clone(),
+        version: plugin.version.clone(),
+        dependencies: Some(plugin.dependencies.clone()),
+        disabled: false
+    }).collect();
+
+    return dependencies::find_dependents(plugin_name, &nodes).iter()
+        .filter_map(|name| plugins.iter().find(|plugin| &plugin.name == name).cloned())
+        .collect();
+}
+
+pub fn unload_plugin(plugin_name: &str) -> Result<(), Error> {
+    let plugin = get_plugin(plugin_name)?;
+    let dependents: Vec<String> = get_dependents(plugin_name).iter().filter(|p| p.get_status() == PluginStatus::Active).map(|p| p.name.clone()).collect();
+    if !dependents.is_empty() {
+        return Err(anyhow!("Plugin '{}' is required by the active plugins: {}", plugin_name, dependents.join(", ")));
+    }
+
+    instance::destroy(&plugin)?;
+    log::info!("Plugin '{}' unloaded", plugin_name);
+
+    return Ok(());
+}
+
+/// Unloads the plugin (when loaded) and loads it again from disk, also used to load newly installed plugins.
+/// Reloads the plugin and then every plugin depending on it, so they check the dependency again and drop
+/// shared modules of the previous instance.
+pub fn reload_plugin(plugin_name: &str) -> Result<Arc<UniChatPlugin>, Error> {
+    let dependents = get_dependents(plugin_name);
+    let plugin = reload_single_plugin(plugin_name)?;
+
+    for dependent in dependents {
+        log::info!("Reloading plugin '{}' after its dependency '{}' was reloaded", dependent.name, plugin_name);
+        if let Err(e) = reload_single_plugin(&dependent.name) {
+            log::error!("Failed to reload plugin '{}': {:?}", dependent.name, e);
+        }
+    }
+
+    return Ok(plugin);
+}
+
+fn reload_single_plugin(plugin_name: &str) -> Result<Arc<UniChatPlugin>, Error> {
+    let plugin_path = match get_plugin(plugin_name) {
+        Ok(plugin) => {
+            let plugin_path = plugin.get_plugin_path();
+            instance::destroy(&plugin)?;
+            plugin_path
+        }
+        Err(_) => find_plugin_path(plugin_name)?
+    };
+
+    let manifest = load_manifest(&plugin_path)?;
+    if manifest.name != plugin_name {
+        return Err(anyhow!("Manifest name '{}' does not match plugin name '{}'", manifest.name, plugin_name));
+    }
+
+    log::info!("Reloading plugin: {} v{}", manifest.name, manifest.version);
+    if let Err(e) = instance::create(&plugin_path, &manifest, &loaded_dependency_errors(&manifest)) {
+        log::error!("Failed to reload plugin: {:?}", e);
+    }
+
+    return get_plugin(plugin_name);
+}
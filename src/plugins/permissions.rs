@@ -0,0 +1,116 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::fmt::Display;
+
+use anyhow::anyhow;
+use anyhow::Error;
+
+/// A capability declared in the plugin manifest `permissions` list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PluginPermission {
+    /// `http:<host>`, the host accepts a `*.` prefix for subdomains or `*` for any host.
+    Http(String),
+    Userstore,
+    Scrapers,
+    EventsEmit,
+    EventsIntercept,
+    Notifications
+}
+
+impl PluginPermission {
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        let value = value.trim();
+
+        if let Some(host) = value.strip_prefix("http:") {
+            let host = host.trim().to_lowercase();
+            if host.is_empty() || host.contains('/') || host.contains(':') {
+                return Err(anyhow!("Invalid http permission '{}', expected 'http:<host>'", value));
+            }
+
+            return Ok(PluginPermission::Http(host));
+        }
+
+        return match value {
+            "userstore" => Ok(PluginPermission::Userstore),
+            "scrapers" => Ok(PluginPermission::Scrapers),
+            "events:emit" => Ok(PluginPermission::EventsEmit),
+            "events:intercept" => Ok(PluginPermission::EventsIntercept),
+            "notifications" => Ok(PluginPermission::Notifications),
+            _ => Err(anyhow!("Unknown permission '{}'", value))
+        };
+    }
+}
+
+impl Display for PluginPermission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            PluginPermission::Http(host) => write!(f, "http:{}", host),
+            PluginPermission::Userstore => write!(f, "userstore"),
+            PluginPermission::Scrapers => write!(f, "scrapers"),
+            PluginPermission::EventsEmit => write!(f, "events:emit"),
+            PluginPermission::EventsIntercept => write!(f, "events:intercept"),
+            PluginPermission::Notifications => write!(f, "notifications")
+        };
+    }
+}
+
+/* ============================================================================================== */
+
+pub fn host_matches(pattern: &str, host: &str) -> bool {
+    let host = host.trim_end_matches('.').to_lowercase();
+
+    if pattern == "*" {
+        return true;
+    } else if let Some(domain) = pattern.strip_prefix("*.") {
+        return host.ends_with(&format!(".{}", domain));
+    }
+
+    return host == pattern;
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PluginPermissions {
+    granted: Vec<PluginPermission>
+}
+
+impl PluginPermissions {
+    /// Parses the manifest entries, invalid entries are skipped and returned as warnings.
+    pub fn from_manifest(entries: &[String]) -> (Self, Vec<String>) {
+        let mut granted: Vec<PluginPermission> = Vec::new();
+        let mut warnings: Vec<String> = Vec::new();
+
+        for entry in entries {
+            match PluginPermission::parse(entry) {
+                Ok(permission) if !granted.contains(&permission) => granted.push(permission),
+                Ok(_) => {}
+                Err(err) => warnings.push(format!("Ignoring permission: {}", err))
+            }
+        }
+
+        return (Self { granted }, warnings);
+    }
+
+    pub fn has(&self, permission: &PluginPermission) -> bool {
+        return self.granted.contains(permission);
+    }
+
+    pub fn has_http(&self) -> bool {
+        return self.granted.iter().any(|p| matches!(p, PluginPermission::Http(_)));
+    }
+
+    pub fn allows_host(&self, host: &str) -> bool {
+        return self.granted.iter().any(|p| matches!(p, PluginPermission::Http(pattern) if host_matches(pattern, host)));
+    }
+
+    pub fn to_strings(&self) -> Vec<String> {
+        return self.granted.iter().map(|p| p.to_string()).collect();
+    }
+}
@@ -0,0 +1,48 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use crate::plugins::permissions::host_matches;
+use crate::plugins::permissions::PluginPermission;
+use crate::plugins::permissions::PluginPermissions;
+
+#[test]
+fn test_parse_permissions() {
+    assert_eq!(PluginPermission::parse("http:API.example.com").unwrap(), PluginPermission::Http(String::from("api.example.com")));
+    assert_eq!(PluginPermission::parse("userstore").unwrap(), PluginPermission::Userstore);
+    assert_eq!(PluginPermission::parse("events:emit").unwrap(), PluginPermission::EventsEmit);
+    assert_eq!(PluginPermission::parse("events:intercept").unwrap(), PluginPermission::EventsIntercept);
+    assert!(PluginPermission::parse("http:").is_err());
+    assert!(PluginPermission::parse("http:https://example.com").is_err());
+    assert!(PluginPermission::parse("filesystem").is_err());
+}
+
+#[test]
+fn test_from_manifest() {
+    let entries = vec![String::from("http:*.twitch.tv"), String::from("notifications"), String::from("notifications"), String::from("root")];
+    let (permissions, warnings) = PluginPermissions::from_manifest(&entries);
+
+    assert_eq!(permissions.to_strings(), vec!["http:*.twitch.tv", "notifications"]);
+    assert_eq!(warnings.len(), 1);
+    assert!(permissions.has(&PluginPermission::Notifications));
+    assert!(!permissions.has(&PluginPermission::Scrapers));
+    assert!(permissions.has_http());
+    assert!(permissions.allows_host("api.twitch.tv"));
+    assert!(!permissions.allows_host("twitch.tv.evil.com"));
+}
+
+#[test]
+fn test_host_matches() {
+    assert!(host_matches("example.com", "EXAMPLE.com"));
+    assert!(!host_matches("example.com", "api.example.com"));
+    assert!(host_matches("*.example.com", "api.example.com"));
+    assert!(!host_matches("*.example.com", "example.com"));
+    assert!(!host_matches("*.example.com", "badexample.com"));
+    assert!(host_matches("*", "anything.local"));
+}
@@ -0,0 +1,115 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use anyhow::Error;
+use indexmap::IndexMap;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::utils::properties;
+use crate::utils::properties::AppPaths;
+use crate::widgets::fields;
+
+/// Loads the plugin `settings.json`, it uses the same format as widget `fields.json`.
+/// Returns the fields and the validation problems, an invalid file results in no settings.
+pub fn load_fields(plugin_path: &Path) -> (IndexMap<String, Value>, Vec<String>) {
+    let settings_path = plugin_path.join("settings.json");
+    if !settings_path.is_file() {
+        return (IndexMap::new(), Vec::new());
+    }
+
+    let raw = match fs::read_to_string(&settings_path) {
+        Ok(raw) => raw,
+        Err(err) => return (IndexMap::new(), vec![format!("Failed to read 'settings.json': {}", err)])
+    };
+
+    let errors = fields::validate(&raw);
+    if !errors.is_empty() {
+        let warnings = errors.into_iter().map(|e| format!("Invalid 'settings.json': {}", e)).collect();
+        return (IndexMap::new(), warnings);
+    }
+
+    let mut fields: IndexMap<String, Value> = serde_json::from_str(&raw).unwrap_or_default();
+    fields.retain(|key, field| !key.starts_with('$') && field.is_object());
+
+    return (fields, Vec::new());
+}
+
+/* ============================================================================================== */
+
+fn state_path(plugin_name: &str) -> PathBuf {
+    return properties::get_app_path(AppPaths::AppData).join("plugin-settings").join(format!("{}.json", plugin_name));
+}
+
+pub fn read_state(plugin_name: &str) -> Map<String, Value> {
+    let raw = fs::read_to_string(state_path(plugin_name)).unwrap_or(String::from("{}"));
+
+    return match serde_json::from_str(&raw) {
+        Ok(state) => state,
+        Err(err) => {
+            log::error!("Failed to parse settings of plugin '{}': {:#?}", plugin_name, err);
+            return Map::new();
+        }
+    };
+}
+
+pub fn write_state(plugin_name: &str, state: &Map<String, Value>) -> Result<(), Error> {
+    let path = state_path(plugin_name);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(&path, serde_json::to_string_pretty(state)?)?;
+    return Ok(());
+}
+
+/* ============================================================================================== */
+
+/// Merges the changes into the stored state, unknown keys are ignored and invalid values are rejected.
+pub fn merge(fields: &IndexMap<String, Value>, state: &Map<String, Value>, changes: &Map<String, Value>) -> Result<Map<String, Value>, Error> {
+    let mut merged = state.clone();
+
+    for (key, value) in changes {
+        let Some(field) = fields.get(key) else {
+            continue;
+        };
+
+        if value.is_null() {
+            merged.remove(key);
+        } else if !fields::is_valid_value(field, value) {
+            return Err(anyhow!("Invalid value for setting '{}'", key));
+        } else {
+            merged.insert(key.clone(), value.clone());
+        }
+    }
+
+    return Ok(merged);
+}
+
+/// Values plugins receive, stored values that still match the field win over the field default.
+pub fn resolve(fields: &IndexMap<String, Value>, state: &Map<String, Value>) -> Map<String, Value> {
+    let mut resolved = Map::new();
+
+    for (key, field) in fields.iter() {
+        if field.get("type").and_then(|t| t.as_str()) == Some("divider") {
+            continue;
+        }
+
+        let value = state.get(key).filter(|v| fields::is_valid_value(field, v)).or(field.get("value")).cloned().unwrap_or(Value::Null);
+        resolved.insert(key.clone(), fields::serialize_value(field, &value));
+    }
+
+    return resolved;
+}
@@ -0,0 +1,51 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use indexmap::IndexMap;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::plugins::plugin_settings::merge;
+use crate::plugins::plugin_settings::resolve;
+
+fn fields() -> IndexMap<String, Value> {
+    return serde_json::from_value(json!({
+        "channel": { "type": "text", "label": "Channel", "value": "unichat" },
+        "divider": { "type": "divider" },
+        "volume": { "type": "number", "label": "Volume", "value": 50, "min": 0, "max": 100 },
+        "sound": { "type": "gallery", "label": "Sound", "fileType": ["audio"] }
+    })).unwrap();
+}
+
+fn state(value: Value) -> Map<String, Value> {
+    return value.as_object().cloned().unwrap();
+}
+
+#[test]
+fn test_resolve_uses_defaults_and_valid_values() {
+    let resolved = resolve(&fields(), &state(json!({ "channel": 10, "volume": 75, "sound": "ding.mp3", "unknown": true })));
+
+    assert_eq!(resolved.get("channel"), Some(&json!("unichat")));
+    assert_eq!(resolved.get("volume"), Some(&json!(75)));
+    assert_eq!(resolved.get("sound"), Some(&json!("/gallery/ding.mp3")));
+    assert!(!resolved.contains_key("divider"));
+    assert!(!resolved.contains_key("unknown"));
+}
+
+#[test]
+fn test_merge_changes() {
+    let current = state(json!({ "channel": "voguh", "volume": 20 }));
+
+    let merged = merge(&fields(), &current, &state(json!({ "volume": 30, "channel": null, "unknown": 1 }))).unwrap();
+    assert_eq!(Value::Object(merged), json!({ "volume": 30 }));
+
+    assert!(merge(&fields(), &current, &state(json!({ "volume": "loud" }))).is_err());
+}
@@ -0,0 +1,128 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use mlua::LuaSerdeExt as _;
+
+use crate::plugins::PluginStatus;
+use crate::plugins::get_plugin;
+use crate::plugins::limits;
+use crate::plugins::limits::ExecutionLimits;
+
+pub struct PluginHttpRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: HashMap<String, String>,
+    pub query: HashMap<String, String>,
+    pub body: Vec<u8>
+}
+
+pub struct PluginHttpResponse {
+    pub status: u16,
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>
+}
+
+#[derive(Debug)]
+pub enum PluginRouteError {
+    NotFound(String),
+    Failed(String)
+}
+
+impl Display for PluginRouteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            PluginRouteError::NotFound(message) => write!(f, "{}", message),
+            PluginRouteError::Failed(message) => write!(f, "{}", message)
+        };
+    }
+}
+
+/* ============================================================================================== */
+
+/// Route paths start with `/`, a trailing `/*` matches the path itself and everything below it.
+pub fn is_valid_route_path(path: &str) -> bool {
+    return path.starts_with('/') && !path.contains("..") && !path.strip_suffix("/*").unwrap_or(path).contains('*');
+}
+
+pub fn route_matches(route_method: &str, route_path: &str, method: &str, path: &str) -> bool {
+    if route_method != "*" && !route_method.eq_ignore_ascii_case(method) {
+        return false;
+    }
+
+    if let Some(prefix) = route_path.strip_suffix("/*") {
+        return path == prefix || path.starts_with(&format!("{}/", prefix));
+    }
+
+    return route_path == path;
+}
+
+pub fn request_to_lua(lua: &mlua::Lua, request: &PluginHttpRequest) -> Result<mlua::Table, mlua::Error> {
+    let table = lua.create_table()?;
+    table.set("method", request.method.clone())?;
+    table.set("path", request.path.clone())?;
+    table.set("headers", request.headers.clone())?;
+    table.set("query", request.query.clone())?;
+    table.set("body", lua.create_string(&request.body)?)?;
+
+    return Ok(table);
+}
+
+/// Reads the `{ status, headers, body }` table returned by a handler, `nil` answers `204 No Content`.
+/// A table body is encoded as JSON.
+pub fn response_from_lua(lua: &mlua::Lua, value: mlua::Value) -> Result<PluginHttpResponse, mlua::Error> {
+    let table = match value {
+        mlua::Value::Nil => return Ok(PluginHttpResponse { status: 204, headers: HashMap::new(), body: Vec::new() }),
+        mlua::Value::Table(table) => table,
+        other => return Err(mlua::Error::runtime(format!("HTTP route handlers must return a table or nil, got '{}'", other.type_name())))
+    };
+
+    let status: u16 = table.get::<Option<u16>>("status")?.unwrap_or(200);
+    let mut headers: HashMap<String, String> = table.get::<Option<HashMap<String, String>>>("headers")?.unwrap_or_default();
+    let body = match table.get::<mlua::Value>("body")? {
+        mlua::Value::Nil => Vec::new(),
+        mlua::Value::String(body) => body.as_bytes().to_vec(),
+        mlua::Value::Table(body) => {
+            let json: serde_json::Value = lua.from_value(mlua::Value::Table(body))?;
+            if !headers.keys().any(|key| key.eq_ignore_ascii_case("content-type")) {
+                headers.insert(String::from("Content-Type"), String::from("application/json"));
+            }
+
+            serde_json::to_vec(&json).map_err(mlua::Error::external)?
+        },
+        other => return Err(mlua::Error::runtime(format!("HTTP route response body must be a string or a table, got '{}'", other.type_name())))
+    };
+
+    return Ok(PluginHttpResponse { status, headers, body });
+}
+
+/// Dispatches a request received on `/plugin/{plugin_name}/{*path}` to the first matching route of the plugin.
+pub fn handle_request(plugin_name: &str, request: PluginHttpRequest) -> Result<PluginHttpResponse, PluginRouteError> {
+    let not_found = || PluginRouteError::NotFound(format!("No route '{} {}' in plugin '{}'", request.method, request.path, plugin_name));
+
+    let plugin = get_plugin(plugin_name).map_err(|_| not_found())?;
+    if plugin.get_status() != PluginStatus::Active {
+        return Err(not_found());
+    }
+
+    let handler = plugin.find_http_route(&request.method, &request.path).ok_or_else(not_found)?;
+    let lua = plugin.get_lua();
+    let result = limits::call_plugin(plugin_name, ExecutionLimits::CALLBACK, || {
+        let table = request_to_lua(&lua, &request)?;
+        return handler.call::<mlua::Value>(table);
+    });
+
+    return result.and_then(|value| response_from_lua(&lua, value)).map_err(|err| {
+        log::error!(target: &format!("plugin:{}", plugin_name), "An error occurred on HTTP route '{} {}': {}", request.method, request.path, err);
+        return PluginRouteError::Failed(err.to_string());
+    });
+}
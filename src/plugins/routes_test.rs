@@ -0,0 +1,66 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::collections::HashMap;
+
+use crate::plugins::routes::PluginHttpRequest;
+use crate::plugins::routes::is_valid_route_path;
+use crate::plugins::routes::request_to_lua;
+use crate::plugins::routes::response_from_lua;
+use crate::plugins::routes::route_matches;
+
+#[test]
+fn test_route_paths() {
+    assert!(is_valid_route_path("/"));
+    assert!(is_valid_route_path("/feed"));
+    assert!(is_valid_route_path("/files/*"));
+    assert!(is_valid_route_path("/é"));
+    assert!(is_valid_route_path("/café/*"));
+    assert!(!is_valid_route_path("feed"));
+    assert!(!is_valid_route_path("/files*"));
+    assert!(!is_valid_route_path("/*/feed"));
+    assert!(!is_valid_route_path("/../feed"));
+
+    assert!(route_matches("GET", "/feed", "get", "/feed"));
+    assert!(!route_matches("POST", "/feed", "GET", "/feed"));
+    assert!(!route_matches("GET", "/feed", "GET", "/feed/items"));
+    assert!(route_matches("*", "/files/*", "PUT", "/files"));
+    assert!(route_matches("*", "/files/*", "PUT", "/files/a/b.txt"));
+    assert!(!route_matches("*", "/files/*", "PUT", "/filesystem"));
+}
+
+#[test]
+fn test_handler_roundtrip() {
+    let lua = mlua::Lua::new();
+    let request = PluginHttpRequest {
+        method: String::from("POST"),
+        path: String::from("/echo"),
+        headers: HashMap::from([(String::from("x-token"), String::from("secret"))]),
+        query: HashMap::from([(String::from("name"), String::from("voguh"))]),
+        body: b"hello".to_vec()
+    };
+
+    let handler: mlua::Function = lua.load(r#"
+        return function(req)
+            return { status = 201, body = { method = req.method, name = req.query.name, token = req.headers["x-token"], body = req.body } }
+        end
+    "#).eval().unwrap();
+    let value = handler.call::<mlua::Value>(request_to_lua(&lua, &request).unwrap()).unwrap();
+    let response = response_from_lua(&lua, value).unwrap();
+
+    assert_eq!(response.status, 201);
+    assert_eq!(response.headers.get("Content-Type").map(String::as_str), Some("application/json"));
+    let body: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+    assert_eq!(body, serde_json::json!({ "method": "POST", "name": "voguh", "token": "secret", "body": "hello" }));
+
+    let empty = response_from_lua(&lua, mlua::Value::Nil).unwrap();
+    assert_eq!(empty.status, 204);
+    assert!(response_from_lua(&lua, mlua::Value::Boolean(true)).is_err());
+}
@@ -0,0 +1,81 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::fmt::Display;
+
+use mlua::LuaSerdeExt as _;
+use serde_json::Value;
+
+use crate::plugins::PluginStatus;
+use crate::plugins::get_plugin;
+use crate::plugins::limits;
+use crate::plugins::limits::ExecutionLimits;
+use crate::utils::jsonrpc::JsonRPCError;
+
+#[derive(Debug)]
+pub enum PluginRpcError {
+    MethodNotFound(String),
+    InvalidParams(String),
+    Failed(String)
+}
+
+impl Display for PluginRpcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            PluginRpcError::MethodNotFound(message) => write!(f, "{}", message),
+            PluginRpcError::InvalidParams(message) => write!(f, "{}", message),
+            PluginRpcError::Failed(message) => write!(f, "{}", message)
+        };
+    }
+}
+
+impl PluginRpcError {
+    /// Plugin failures use the `-32000` server error code, the other errors have their own JSON-RPC codes.
+    pub fn into_jsonrpc_error(self, id: String) -> JsonRPCError {
+        return match self {
+            PluginRpcError::MethodNotFound(message) => JsonRPCError::method_not_found(Some(id), Some(message)),
+            PluginRpcError::InvalidParams(message) => JsonRPCError::invalid_params(Some(id), Some(message)),
+            PluginRpcError::Failed(message) => JsonRPCError::server_error(Some(id), -32000, String::from("Plugin error"), Some(message))
+        };
+    }
+}
+
+pub fn is_valid_method_name(name: &str) -> bool {
+    return !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.');
+}
+
+/// Converts every param to a LUA value, a param the plugin state can not hold is an invalid param.
+pub fn params_to_lua(lua: &mlua::Lua, params: &[Value]) -> Result<mlua::MultiValue, PluginRpcError> {
+    let args = params.iter().map(|param| lua.to_value(param)).collect::<Result<mlua::MultiValue, mlua::Error>>();
+    return args.map_err(|e| PluginRpcError::InvalidParams(e.to_string()));
+}
+
+/// Calls a method registered with `UniChatAPI:register_rpc_method`, `method` is `plugin_name:method_name`.
+/// Every param is passed as an argument and the first value returned by the method is the result.
+pub fn call_method(method: &str, params: Vec<Value>) -> Result<Value, PluginRpcError> {
+    let not_found = || PluginRpcError::MethodNotFound(format!("Method '{}' not found", method));
+
+    let (plugin_name, method_name) = method.split_once(':').ok_or_else(not_found)?;
+    let plugin = get_plugin(plugin_name).map_err(|_| not_found())?;
+    if plugin.get_status() != PluginStatus::Active {
+        return Err(not_found());
+    }
+
+    let callback = plugin.get_rpc_method(method_name).ok_or_else(not_found)?;
+    let lua = plugin.get_lua();
+    let args = params_to_lua(&lua, &params)?;
+
+    let result = limits::call_plugin(plugin_name, ExecutionLimits::CALLBACK, || callback.call::<mlua::Value>(args)).map_err(|err| {
+        log::error!(target: &format!("plugin:{}", plugin_name), "An error occurred on RPC method '{}': {}", method_name, err);
+        return PluginRpcError::Failed(err.to_string());
+    })?;
+
+    return lua.from_value(result).map_err(|e| PluginRpcError::Failed(format!("Method '{}' returned a value that can not be serialized: {}", method, e)));
+}
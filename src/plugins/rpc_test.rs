@@ -0,0 +1,59 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use serde_json::json;
+
+use crate::plugins::rpc::PluginRpcError;
+use crate::plugins::rpc::call_method;
+use crate::plugins::rpc::is_valid_method_name;
+use crate::plugins::rpc::params_to_lua;
+
+#[test]
+fn test_method_names() {
+    assert!(is_valid_method_name("get_points"));
+    assert!(is_valid_method_name("points.get-top10"));
+    assert!(!is_valid_method_name(""));
+    assert!(!is_valid_method_name("get points"));
+    assert!(!is_valid_method_name("plugin:method"));
+    assert!(!is_valid_method_name("métodos"));
+}
+
+#[test]
+fn test_method_not_found() {
+    assert!(matches!(call_method("no-separator", Vec::new()), Err(PluginRpcError::MethodNotFound(_))));
+    assert!(matches!(call_method("missing-plugin:method", Vec::new()), Err(PluginRpcError::MethodNotFound(_))));
+    assert!(matches!(call_method(":method", Vec::new()), Err(PluginRpcError::MethodNotFound(_))));
+}
+
+#[test]
+fn test_params_to_lua() {
+    let lua = mlua::Lua::new();
+    let args = params_to_lua(&lua, &[json!(1), json!("two"), json!({ "three": [3] })]).unwrap();
+    assert_eq!(args.len(), 3);
+
+    // A param the plugin state can not allocate is reported as invalid params instead of a plugin failure.
+    lua.set_memory_limit(lua.used_memory() + 64 * 1024).unwrap();
+    let large = json!("x".repeat(1024 * 1024));
+    assert!(matches!(params_to_lua(&lua, &[large]), Err(PluginRpcError::InvalidParams(_))));
+}
+
+#[test]
+fn test_jsonrpc_error_codes() {
+    let not_found = PluginRpcError::MethodNotFound(String::from("missing")).into_jsonrpc_error(String::from("1"));
+    assert_eq!(not_found.error().code(), -32601);
+    assert_eq!(not_found.id(), Some(String::from("1")));
+
+    let invalid_params = PluginRpcError::InvalidParams(String::from("invalid")).into_jsonrpc_error(String::from("2"));
+    assert_eq!(invalid_params.error().code(), -32602);
+
+    let failed = PluginRpcError::Failed(String::from("boom")).into_jsonrpc_error(String::from("3"));
+    assert_eq!(failed.error().code(), -32000);
+    assert_eq!(failed.error().data(), Some(json!("boom")));
+}
@@ -8,26 +8,13 @@
  * SPDX-License-Identifier: EPL-2.0
  ******************************************************************************/
 
-use std::sync::Arc;
-use std::sync::OnceLock;
-
-use anyhow::anyhow;
 use anyhow::Error;
 
-const LUA_RUNTIME_ONCE_LOCK_KEY: &str = "Plugins::LUA_RUNTIME";
-static LUA_RUNTIME: OnceLock<Arc<mlua::Lua>> = OnceLock::new();
-
-pub fn get() -> Result<Arc<mlua::Lua>, Error> {
-    let lua = LUA_RUNTIME.get().ok_or(anyhow!("{} was not initialized", LUA_RUNTIME_ONCE_LOCK_KEY))?;
-    return Ok(lua.clone());
-}
-
-pub fn new() -> Result<(), Error> {
-    if LUA_RUNTIME.get().is_some() {
-        return Err(anyhow!("{} is already initialized", LUA_RUNTIME_ONCE_LOCK_KEY));
-    }
+use crate::plugins::limits;
 
-    log::info!("Configuring LUA runtime");
+/// Creates a sandboxed LUA state, every plugin runs on its own state so it can be torn down alone.
+pub fn create() -> Result<mlua::Lua, Error> {
+    log::debug!("Configuring LUA runtime");
     let lua = mlua::Lua::new();
     let _globals = lua.globals();
 
@@ -68,8 +55,9 @@ pub fn new() -> Result<(), Error> {
     /* <====================[ End LUA Standard Library ]====================> */
 
     lua.set_globals(_globals)?;
+    limits::install(&lua)?;
     log::debug!("LUA runtime configured successfully");
 
-    return LUA_RUNTIME.set(Arc::new(lua)).map_err(|_| anyhow!("{} was already initialized", LUA_RUNTIME_ONCE_LOCK_KEY));
+    return Ok(lua);
 }
 
@@ -16,10 +16,12 @@ use std::sync::RwLock;
 use anyhow::anyhow;
 use anyhow::Error;
 use tauri::Listener as _;
+use tauri::Manager as _;
 use tauri::webview::PageLoadPayload;
 use tauri::WebviewWindow;
 use tauri::Wry;
 
+use crate::get_app_handle;
 use crate::scraper::status::ScraperStatusEvent;
 use crate::scraper::utils::decode_url;
 use crate::utils::get_current_timestamp;
@@ -181,3 +183,19 @@ pub fn register_scraper(scraper: Arc<dyn UniChatScraper + Send + Sync>) -> Resul
 
     return Ok(window);
 }
+
+/// Removes a scraper registered at runtime and destroys its webview window.
+pub fn unregister_scraper(scraper_id: &str) -> Result<(), Error> {
+    {
+        let mut scrapers = SCRAPERS.write().map_err(|_| anyhow!("{} lock poisoned", LAZY_LOCK_NAME))?;
+        if scrapers.remove(scraper_id).is_none() {
+            return Err(anyhow!("Scraper with ID '{}' is not registered", scraper_id));
+        }
+    }
+
+    if let Some(window) = get_app_handle().get_webview_window(scraper_id) {
+        window.destroy()?;
+    }
+
+    return Ok(());
+}
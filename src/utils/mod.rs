@@ -26,8 +26,10 @@ pub mod render_emitter;
 #[cfg(test)] mod semver_test;
 pub mod semver;
 pub mod settings;
+#[cfg(test)] pub mod test_utils;
 pub mod ureq;
 pub mod userstore;
+#[cfg(test)] mod utils_test;
 
 pub fn is_dev() -> bool {
     return cfg!(debug_assertions) || tauri::is_dev();
@@ -145,6 +147,11 @@ pub fn is_valid_twitch_channel_name(channel_name: &str) -> bool {
 /* ================================================================================================================== */
 
 pub fn safe_guard_path(base_path: &path::PathBuf, concat_str: &str) -> Result<path::PathBuf, Error> {
+    // `path::absolute` keeps `..` components on Unix, so they must be rejected before joining.
+    if path::Path::new(concat_str).components().any(|c| !matches!(c, path::Component::Normal(_) | path::Component::CurDir)) {
+        return Err(anyhow!("Access to path '{}' is not allowed", concat_str));
+    }
+
     let concatenated_path = base_path.join(concat_str);
     let resolved_path = path::absolute(concatenated_path)?;
     if !resolved_path.starts_with(base_path) {
@@ -8,6 +8,7 @@
  * SPDX-License-Identifier: EPL-2.0
  ******************************************************************************/
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::LazyLock;
 use std::sync::OnceLock;
@@ -45,6 +46,9 @@ pub const SETTINGS_OPEN_TO_LAN_KEY: &str = "settings:open-to-lan";
 pub const SETTINGS_LOG_SCRAPER_EVENTS: &str = "settings:log-scraper-events";
 pub const SETTINGS_OPEN_SCRAPER_WEBVIEW_KEY: &str = "settings:open-scraper-webview";
 pub const SETTINGS_CURRENCY_TARGET_KEY: &str = "settings:currency-target";
+pub const SETTINGS_CATALOG_URL_KEY: &str = "settings:catalog-url";
+pub const SETTINGS_DISABLED_PLUGINS_KEY: &str = "settings:disabled-plugins";
+pub const SETTINGS_ACKNOWLEDGED_PLUGIN_PERMISSIONS_KEY: &str = "settings:acknowledged-plugin-permissions";
 
 const SCRAPER_KEY_TEMPLATE: &str = "scraper:{}:{}";
 fn store_mount_scraper_key(scraper_id: &str, key: &str) -> String {
@@ -244,6 +248,36 @@ static MIGRATIONS: LazyLock<Vec<Box<dyn Fn(&Arc<Store<tauri::Wry>>) -> Result<()
                 store.set(currency_target_key, raw_value);
             }
 
+            return Ok(());
+        }),
+        Box::new(|store| {
+            let catalog_url_key = "settings:catalog-url";
+            if store.get(catalog_url_key).is_none() {
+                log::info!("Setting default value for '{}' setting", catalog_url_key);
+                let raw_value = serde_json::to_value("")?;
+                store.set(catalog_url_key, raw_value);
+            }
+
+            return Ok(());
+        }),
+        Box::new(|store| {
+            let disabled_plugins_key = "settings:disabled-plugins";
+            if store.get(disabled_plugins_key).is_none() {
+                log::info!("Setting default value for '{}' setting", disabled_plugins_key);
+                let raw_value = serde_json::to_value(Vec::<String>::new())?;
+                store.set(disabled_plugins_key, raw_value);
+            }
+
+            return Ok(());
+        }),
+        Box::new(|store| {
+            let acknowledged_plugin_permissions_key = "settings:acknowledged-plugin-permissions";
+            if store.get(acknowledged_plugin_permissions_key).is_none() {
+                log::info!("Setting default value for '{}' setting", acknowledged_plugin_permissions_key);
+                let raw_value = serde_json::to_value(HashMap::<String, Vec<String>>::new())?;
+                store.set(acknowledged_plugin_permissions_key, raw_value);
+            }
+
             return Ok(());
         })
     ]
@@ -0,0 +1,43 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::path::PathBuf;
+
+use crate::utils::safe_guard_path;
+
+fn base_path() -> PathBuf {
+    return std::env::temp_dir().join("unichat-safe-guard-base");
+}
+
+#[test]
+fn test_safe_guard_path_accepts_caller_paths() {
+    let base = base_path();
+
+    // Widget and gallery assets, plugin assets and scraper scripts.
+    assert_eq!(safe_guard_path(&base, "image.png").unwrap(), base.join("image.png"));
+    assert_eq!(safe_guard_path(&base, "images/icons/icon.svg").unwrap(), base.join("images/icons/icon.svg"));
+    assert_eq!(safe_guard_path(&base, "./scraper.js").unwrap(), base.join("scraper.js"));
+    assert_eq!(safe_guard_path(&base, "fonts/Noto Sans é.woff2").unwrap(), base.join("fonts/Noto Sans é.woff2"));
+
+    // Plugin `require("lib.utils")` resolves to `lib/utils.lua`.
+    assert_eq!(safe_guard_path(&base, "lib/utils.lua").unwrap(), base.join("lib/utils.lua"));
+}
+
+#[test]
+fn test_safe_guard_path_rejects_escaping_paths() {
+    let base = base_path();
+
+    assert!(safe_guard_path(&base, "../secret.txt").is_err());
+    assert!(safe_guard_path(&base, "images/../../secret.txt").is_err());
+    assert!(safe_guard_path(&base, "/etc/passwd").is_err());
+
+    // Parent components are rejected even when they would stay inside the base path.
+    assert!(safe_guard_path(&base, "images/../image.png").is_err());
+}
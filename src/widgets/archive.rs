@@ -0,0 +1,292 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use anyhow::Error;
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::UNICHAT_DISPLAY_NAME;
+use crate::UNICHAT_VERSION;
+use crate::utils::base64;
+use crate::utils::safe_guard_path;
+use crate::widgets::fields;
+use crate::widgets::is_reserved_widget_name;
+use crate::widgets::is_valid_widget_name;
+use crate::widgets::manifest::WidgetManifestYAML;
+
+pub const ARCHIVE_EXTENSION: &str = "ucwidget";
+const ARCHIVE_FORMAT: &str = "unichat-widget";
+const ARCHIVE_FORMAT_VERSION: u32 = 1;
+const MAX_ARCHIVE_SIZE: u64 = 64 * 1024 * 1024;
+
+/// A widget packed as a single JSON file, every file content is stored as base64.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetArchive {
+    pub format: String,
+    pub format_version: u32,
+    pub name: String,
+    pub unichat_version: String,
+    pub files: IndexMap<String, String>
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetArchivePreview {
+    pub name: String,
+    pub suggested_name: String,
+    pub conflict: bool,
+    pub unichat_version: String,
+    pub manifest: Option<WidgetManifestYAML>,
+    pub files: Vec<String>,
+    pub size: usize,
+    pub has_fieldstate: bool,
+    pub instances: Vec<String>,
+    pub warnings: Vec<String>
+}
+
+fn is_fieldstate_file(relative_path: &str) -> bool {
+    return relative_path.starts_with("fieldstate.json") || relative_path.starts_with("instances/");
+}
+
+fn collect_files(base_path: &Path, current_path: &Path, include_fieldstate: bool, files: &mut Vec<(String, PathBuf)>) -> Result<(), Error> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(current_path)?.flatten().map(|e| e.path()).collect();
+    entries.sort();
+
+    for path in entries {
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        if file_name.starts_with(".") {
+            continue;
+        }
+
+        if path.is_dir() {
+            collect_files(base_path, &path, include_fieldstate, files)?;
+            continue;
+        }
+
+        let relative_path = path.strip_prefix(base_path)?.components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<String>>()
+            .join("/");
+
+        if !include_fieldstate && is_fieldstate_file(&relative_path) {
+            continue;
+        }
+
+        files.push((relative_path, path));
+    }
+
+    return Ok(());
+}
+
+/// Lists the widget files (hidden ones are skipped) as `/` separated relative paths.
+pub fn list_widget_files(widget_path: &Path, include_fieldstate: bool) -> Result<Vec<(String, PathBuf)>, Error> {
+    let mut files = Vec::new();
+    collect_files(widget_path, widget_path, include_fieldstate, &mut files)?;
+
+    return Ok(files);
+}
+
+fn copy_fieldstate(from_path: &Path, to_path: &Path) -> Result<(), Error> {
+    let fieldstate_path = from_path.join("fieldstate.json");
+    if fieldstate_path.is_file() {
+        fs::copy(&fieldstate_path, to_path.join("fieldstate.json"))?;
+    }
+
+    let instances_path = from_path.join("instances");
+    if instances_path.is_dir() {
+        fs::create_dir_all(to_path.join("instances"))?;
+        for entry in fs::read_dir(&instances_path)?.flatten() {
+            if entry.path().is_file() {
+                fs::copy(entry.path(), to_path.join("instances").join(entry.file_name()))?;
+            }
+        }
+    }
+
+    return Ok(());
+}
+
+impl WidgetArchive {
+    pub fn from_widget_path(widget_path: &Path, include_fieldstate: bool) -> Result<Self, Error> {
+        let name = widget_path.file_name().ok_or(anyhow!("Invalid widget folder name"))?.to_string_lossy().to_string();
+
+        let mut files = IndexMap::new();
+        for (relative_path, path) in list_widget_files(widget_path, include_fieldstate)? {
+            files.insert(relative_path, base64::encode(fs::read(&path)?));
+        }
+
+        return Ok(Self {
+            format: String::from(ARCHIVE_FORMAT),
+            format_version: ARCHIVE_FORMAT_VERSION,
+            name: name,
+            unichat_version: String::from(UNICHAT_VERSION),
+            files: files
+        });
+    }
+
+    pub fn read(archive_path: &Path) -> Result<Self, Error> {
+        if fs::metadata(archive_path)?.len() > MAX_ARCHIVE_SIZE {
+            return Err(anyhow!("Archive is larger than {} MiB", MAX_ARCHIVE_SIZE / 1024 / 1024));
+        }
+
+        return Self::from_slice(&fs::read(archive_path)?);
+    }
+
+    pub fn from_slice(data: &[u8]) -> Result<Self, Error> {
+        if data.len() as u64 > MAX_ARCHIVE_SIZE {
+            return Err(anyhow!("Archive is larger than {} MiB", MAX_ARCHIVE_SIZE / 1024 / 1024));
+        }
+
+        let archive: Self = serde_json::from_slice(data)?;
+        if archive.format != ARCHIVE_FORMAT {
+            return Err(anyhow!("File is not a widget archive"));
+        } else if archive.format_version > ARCHIVE_FORMAT_VERSION {
+            return Err(anyhow!("Archive format version {} is not supported", archive.format_version));
+        } else if !is_valid_widget_name(&archive.name) {
+            return Err(anyhow!("Invalid widget name '{}'", archive.name));
+        } else if !archive.files.contains_key("widget.html") && !archive.files.contains_key("main.html") {
+            return Err(anyhow!("Archive does not contain a widget HTML entrypoint"));
+        }
+
+        return Ok(archive);
+    }
+
+    pub fn write(&self, archive_path: &Path) -> Result<(), Error> {
+        fs::write(archive_path, serde_json::to_string(self)?)?;
+        return Ok(());
+    }
+
+    pub fn set_file(&mut self, relative_path: &str, content: &[u8]) {
+        self.files.insert(String::from(relative_path), base64::encode(content));
+    }
+
+    fn decode_file(&self, relative_path: &str) -> Option<Vec<u8>> {
+        return self.files.get(relative_path).and_then(|content| base64::decode(content).ok());
+    }
+
+    /// Extracts every file into `target_path`, failing on the first unsafe path or invalid content.
+    fn extract(&self, target_path: &PathBuf) -> Result<(), Error> {
+        for (relative_path, content) in &self.files {
+            let file_path = safe_guard_path(target_path, relative_path)?;
+            let content = base64::decode(content).map_err(|e| anyhow!("Invalid content for file '{}': {}", relative_path, e))?;
+
+            if let Some(parent) = file_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            fs::write(&file_path, content)?;
+        }
+
+        return Ok(());
+    }
+
+    /* ====================================================================== */
+
+    pub fn preview(&self, widgets_path: &Path) -> Result<WidgetArchivePreview, Error> {
+        let widgets_path = widgets_path.to_path_buf();
+        let mut warnings = Vec::new();
+        let mut size = 0;
+
+        for (relative_path, content) in &self.files {
+            safe_guard_path(&widgets_path.join(&self.name), relative_path)?;
+            size += base64::decode(content).map_err(|e| anyhow!("Invalid content for file '{}': {}", relative_path, e))?.len();
+        }
+
+        let mut manifest = None;
+        if let Some(raw) = self.decode_file("widget.yaml") {
+            match serde_saphyr::from_str::<WidgetManifestYAML>(&String::from_utf8_lossy(&raw)) {
+                Ok(parsed) => {
+                    if let Ok(Some(false)) = parsed.is_compatible() {
+                        warnings.push(format!("INCOMPATIBLE_VERSION: Requires {} version '{}', current version is '{}'", UNICHAT_DISPLAY_NAME, parsed.unichat.clone().unwrap_or_default(), UNICHAT_VERSION));
+                    }
+
+                    manifest = Some(parsed);
+                }
+                Err(err) => warnings.push(format!("INVALID_MANIFEST: {}", err))
+            }
+        }
+
+        if let Some(raw) = self.decode_file("fields.json") {
+            for err in fields::validate(&String::from_utf8_lossy(&raw)) {
+                warnings.push(format!("FIELDS_ERROR: {}", err));
+            }
+        }
+
+        let mut instances: Vec<String> = self.files.keys()
+            .filter_map(|p| p.strip_prefix("instances/").and_then(|p| p.strip_suffix(".json")))
+            .map(String::from)
+            .collect();
+        instances.sort();
+
+        let mut suggested_name = self.name.clone();
+        let mut suffix = 2;
+        while widgets_path.join(&suggested_name).exists() || is_reserved_widget_name(&suggested_name) {
+            suggested_name = format!("{}-{}", self.name, suffix);
+            suffix += 1;
+        }
+
+        return Ok(WidgetArchivePreview {
+            name: self.name.clone(),
+            conflict: suggested_name != self.name,
+            suggested_name: suggested_name,
+            unichat_version: self.unichat_version.clone(),
+            manifest: manifest,
+            files: self.files.keys().cloned().collect(),
+            size: size,
+            has_fieldstate: self.files.contains_key("fieldstate.json"),
+            instances: instances,
+            warnings: warnings
+        });
+    }
+
+    /// Installs the archive as `widgets_path/name`, an overwritten widget keeps its fieldstate
+    /// unless the archive ships one.
+    pub fn install(&self, widgets_path: &Path, name: &str, overwrite: bool) -> Result<PathBuf, Error> {
+        if !is_valid_widget_name(name) {
+            return Err(anyhow!("Invalid widget name '{}'", name));
+        }
+
+        let widgets_path = widgets_path.to_path_buf();
+        let widget_path = safe_guard_path(&widgets_path, name)?;
+        if widget_path.exists() && !overwrite {
+            return Err(anyhow!("Widget '{}' already exists", name));
+        }
+
+        // Dot-prefixed folders are ignored by the widgets loader.
+        let staging_path = safe_guard_path(&widgets_path, &format!(".{}.import", name))?;
+        if staging_path.exists() {
+            fs::remove_dir_all(&staging_path)?;
+        }
+        fs::create_dir_all(&staging_path)?;
+
+        let mut result = self.extract(&staging_path);
+        if result.is_ok() && widget_path.exists() && !self.files.keys().any(|p| is_fieldstate_file(p)) {
+            result = copy_fieldstate(&widget_path, &staging_path);
+        }
+
+        if let Err(err) = result {
+            let _ = fs::remove_dir_all(&staging_path);
+            return Err(err);
+        }
+
+        if widget_path.exists() {
+            fs::remove_dir_all(&widget_path)?;
+        }
+        fs::rename(&staging_path, &widget_path)?;
+
+        return Ok(widget_path);
+    }
+}
@@ -0,0 +1,144 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::utils::test_utils::temp_dir;
+use crate::widgets::WIDGETS;
+use crate::widgets::WidgetMetadata;
+use crate::widgets::WidgetSource;
+use crate::widgets::archive::WidgetArchive;
+
+fn create_widget(widgets_path: &Path, name: &str) -> PathBuf {
+    let widget_path = widgets_path.join(name);
+    fs::create_dir_all(widget_path.join("assets")).unwrap();
+    fs::create_dir_all(widget_path.join("instances")).unwrap();
+    fs::write(widget_path.join("widget.html"), "<div>{{text}}</div>").unwrap();
+    fs::write(widget_path.join("fields.json"), r#"{ "text": { "type": "text", "label": "Text" } }"#).unwrap();
+    fs::write(widget_path.join("fieldstate.json"), r#"{ "text": "main" }"#).unwrap();
+    fs::write(widget_path.join("instances").join("other.json"), r#"{ "text": "other" }"#).unwrap();
+    fs::write(widget_path.join("assets").join("image.png"), [0u8, 1, 2, 255]).unwrap();
+    fs::write(widget_path.join(".hidden"), "ignored").unwrap();
+
+    return widget_path;
+}
+
+#[test]
+fn test_export_and_install() {
+    let source_path = temp_dir("widget-archive-export-source");
+    let target_path = temp_dir("widget-archive-export-target");
+    let widget_path = create_widget(&source_path, "my-widget");
+
+    let archive = WidgetArchive::from_widget_path(&widget_path, false).unwrap();
+    assert_eq!(archive.name, "my-widget");
+    assert_eq!(archive.files.keys().cloned().collect::<Vec<String>>(), vec!["assets/image.png", "fields.json", "widget.html"]);
+
+    let archive_path = target_path.join("my-widget.ucwidget");
+    archive.write(&archive_path).unwrap();
+    let archive = WidgetArchive::read(&archive_path).unwrap();
+
+    let preview = archive.preview(&target_path).unwrap();
+    assert!(!preview.conflict);
+    assert!(!preview.has_fieldstate);
+    assert_eq!(preview.size, 4 + 19 + 47);
+    assert!(preview.warnings.is_empty());
+
+    let installed_path = archive.install(&target_path, "my-widget", false).unwrap();
+    assert_eq!(fs::read(installed_path.join("assets").join("image.png")).unwrap(), vec![0u8, 1, 2, 255]);
+    assert!(!installed_path.join("fieldstate.json").exists());
+    assert!(!installed_path.join(".hidden").exists());
+    assert!(!target_path.join(".my-widget.import").exists());
+
+    let _ = fs::remove_dir_all(&source_path);
+    let _ = fs::remove_dir_all(&target_path);
+}
+
+#[test]
+fn test_export_with_fieldstate() {
+    let source_path = temp_dir("widget-archive-fieldstate");
+    let widget_path = create_widget(&source_path, "my-widget");
+
+    let archive = WidgetArchive::from_widget_path(&widget_path, true).unwrap();
+    let preview = archive.preview(&source_path).unwrap();
+    assert!(preview.has_fieldstate);
+    assert_eq!(preview.instances, vec!["other"]);
+
+    let _ = fs::remove_dir_all(&source_path);
+}
+
+#[test]
+fn test_install_conflicts() {
+    let widgets_path = temp_dir("widget-archive-conflicts");
+    let widget_path = create_widget(&widgets_path, "my-widget");
+    create_widget(&widgets_path, "my-widget-2");
+
+    let mut archive = WidgetArchive::from_widget_path(&widget_path, false).unwrap();
+    archive.set_file("widget.html", b"<div>updated</div>");
+
+    let preview = archive.preview(&widgets_path).unwrap();
+    assert!(preview.conflict);
+    assert_eq!(preview.suggested_name, "my-widget-3");
+
+    assert!(archive.install(&widgets_path, "my-widget", false).is_err());
+    assert!(archive.install(&widgets_path, "../my-widget", false).is_err());
+
+    archive.install(&widgets_path, "my-widget", true).unwrap();
+    assert_eq!(fs::read_to_string(widget_path.join("widget.html")).unwrap(), "<div>updated</div>");
+    assert_eq!(fs::read_to_string(widget_path.join("fieldstate.json")).unwrap(), r#"{ "text": "main" }"#);
+    assert!(widget_path.join("instances").join("other.json").is_file());
+
+    let _ = fs::remove_dir_all(&widgets_path);
+}
+
+#[test]
+fn test_rejects_path_traversal() {
+    let widgets_path = temp_dir("widget-archive-traversal");
+    let widget_path = create_widget(&widgets_path, "my-widget");
+
+    let mut archive = WidgetArchive::from_widget_path(&widget_path, false).unwrap();
+    archive.set_file("../evil.js", b"alert(1)");
+
+    assert!(archive.preview(&widgets_path).is_err());
+    assert!(archive.install(&widgets_path, "evil-widget", false).is_err());
+    assert!(!widgets_path.join("evil.js").exists());
+    assert!(!widgets_path.join("evil-widget").exists());
+    assert!(!widgets_path.join(".evil-widget.import").exists());
+
+    let _ = fs::remove_dir_all(&widgets_path);
+}
+
+#[test]
+fn test_preview_reserved_names() {
+    let widgets_path = temp_dir("widget-archive-reserved");
+    let widget_path = create_widget(&widgets_path, "my-widget");
+
+    let system_widget = WidgetMetadata {
+        path: widgets_path.join("archive-system-widget"),
+        rest_path: String::from("archive-system-widget"),
+        widget_source: WidgetSource::System,
+        manifest: None,
+        preview_url: None,
+        fork: None,
+        warnings: Vec::new()
+    };
+    WIDGETS.write().unwrap().insert(system_widget.rest_path.clone(), system_widget);
+
+    // The system widget is not in the user widgets folder, it would still be shadowed by the import.
+    let mut archive = WidgetArchive::from_widget_path(&widget_path, false).unwrap();
+    archive.name = String::from("archive-system-widget");
+    let preview = archive.preview(&widgets_path).unwrap();
+    assert!(preview.conflict);
+    assert_eq!(preview.suggested_name, "archive-system-widget-2");
+
+    WIDGETS.write().unwrap().remove("archive-system-widget");
+    let _ = fs::remove_dir_all(&widgets_path);
+}
@@ -0,0 +1,371 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use crate::events::unichat::UNICHAT_EVENT_TYPES;
+use crate::widgets::filter::FILTER_FIELDS;
+use crate::widgets::template::encode_uri_component;
+
+/// Mirrors the rules of `widgets/fields-schema.json`, keep both in sync.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum PropertyKind {
+    String,
+    Number,
+    Boolean,
+    StringArray,
+    StringMap,
+    FileTypes,
+    EventTypes,
+    NumberRange,
+    PositiveInteger,
+    StringOrStringArray,
+    Any
+}
+
+impl PropertyKind {
+    fn describe(&self) -> &'static str {
+        return match self {
+            PropertyKind::String => "a string",
+            PropertyKind::Number => "a number",
+            PropertyKind::Boolean => "a boolean",
+            PropertyKind::StringArray => "an array of strings",
+            PropertyKind::StringMap => "an object of strings",
+            PropertyKind::FileTypes => "a non-empty array of unique file types",
+            PropertyKind::EventTypes => "an array of unique event types",
+            PropertyKind::NumberRange => "an object with numeric `min` and `max`",
+            PropertyKind::PositiveInteger => "a positive integer",
+            PropertyKind::StringOrStringArray => "a string or an array of strings",
+            PropertyKind::Any => "any JSON value"
+        };
+    }
+}
+
+struct FieldRule {
+    types: &'static [&'static str],
+    properties: &'static [(&'static str, PropertyKind)],
+    required: &'static [&'static str]
+}
+
+const FILE_TYPES: [&str; 4] = ["image", "video", "audio", "file"];
+
+const FIELD_RULES: &[FieldRule] = &[
+    FieldRule {
+        types: &["text", "textarea"],
+        properties: &[("value", PropertyKind::String)],
+        required: &["label"]
+    },
+    FieldRule {
+        types: &["number"],
+        properties: &[
+            ("value", PropertyKind::Number),
+            ("min", PropertyKind::Number),
+            ("max", PropertyKind::Number),
+            ("step", PropertyKind::Number)
+        ],
+        required: &["label"]
+    },
+    FieldRule {
+        types: &["checkbox", "switch"],
+        properties: &[("value", PropertyKind::Boolean)],
+        required: &["label"]
+    },
+    FieldRule {
+        types: &["colorpicker"],
+        properties: &[("value", PropertyKind::String), ("swatches", PropertyKind::StringArray)],
+        required: &["label"]
+    },
+    FieldRule {
+        types: &["dropdown"],
+        properties: &[("value", PropertyKind::String), ("options", PropertyKind::StringMap)],
+        required: &["label", "options"]
+    },
+    FieldRule {
+        types: &["filepicker"],
+        properties: &[("value", PropertyKind::String), ("fileType", PropertyKind::FileTypes)],
+        required: &["label", "fileType"]
+    },
+    FieldRule {
+        types: &["gallery"],
+        properties: &[("value", PropertyKind::String), ("fileType", PropertyKind::FileTypes)],
+        required: &["label"]
+    },
+    FieldRule {
+        types: &["font"],
+        properties: &[("value", PropertyKind::String), ("fonts", PropertyKind::StringArray)],
+        required: &["label"]
+    },
+    FieldRule {
+        types: &["eventtypes"],
+        properties: &[("value", PropertyKind::EventTypes), ("options", PropertyKind::EventTypes)],
+        required: &["label"]
+    },
+    FieldRule {
+        types: &["range"],
+        properties: &[
+            ("value", PropertyKind::NumberRange),
+            ("min", PropertyKind::Number),
+            ("max", PropertyKind::Number),
+            ("step", PropertyKind::Number)
+        ],
+        required: &["label", "min", "max"]
+    },
+    FieldRule {
+        types: &["json"],
+        properties: &[("value", PropertyKind::Any)],
+        required: &["label"]
+    },
+    FieldRule {
+        types: &["divider"],
+        properties: &[],
+        required: &[]
+    }
+];
+
+/// Properties accepted by every field type, only `group` and `label` are allowed on dividers.
+const COMMON_PROPERTIES: [(&str, PropertyKind); 5] = [
+    ("group", PropertyKind::String),
+    ("label", PropertyKind::String),
+    ("description", PropertyKind::String),
+    ("version", PropertyKind::PositiveInteger),
+    ("renamedFrom", PropertyKind::StringOrStringArray)
+];
+
+fn matches_kind(value: &Value, kind: PropertyKind) -> bool {
+    return match kind {
+        PropertyKind::String => value.is_string(),
+        PropertyKind::Number => value.is_number(),
+        PropertyKind::Boolean => value.is_boolean(),
+        PropertyKind::StringArray => value.as_array().is_some_and(|items| items.iter().all(|i| i.is_string())),
+        PropertyKind::StringMap => value.as_object().is_some_and(|items| items.values().all(|i| i.is_string())),
+        PropertyKind::FileTypes => {
+            let Some(items) = value.as_array() else {
+                return false;
+            };
+
+            let mut seen = HashSet::new();
+            return !items.is_empty() && items.iter().all(|i| i.as_str().is_some_and(|s| FILE_TYPES.contains(&s) && seen.insert(s)));
+        }
+        PropertyKind::EventTypes => {
+            let Some(items) = value.as_array() else {
+                return false;
+            };
+
+            let mut seen = HashSet::new();
+            return items.iter().all(|i| i.as_str().is_some_and(|s| UNICHAT_EVENT_TYPES.contains(&s) && seen.insert(s)));
+        }
+        PropertyKind::NumberRange => value.as_object().is_some_and(|obj| obj.len() == 2 && obj.get("min").is_some_and(|v| v.is_number()) && obj.get("max").is_some_and(|v| v.is_number())),
+        PropertyKind::PositiveInteger => value.as_u64().is_some_and(|v| v > 0),
+        PropertyKind::StringOrStringArray => value.is_string() || matches_kind(value, PropertyKind::StringArray),
+        PropertyKind::Any => true
+    };
+}
+
+fn validate_field(key: &str, field: &Value, errors: &mut Vec<String>) {
+    let Some(field) = field.as_object() else {
+        errors.push(format!("Field '{}' must be an object", key));
+        return;
+    };
+
+    let field_type = match field.get("type") {
+        Some(Value::String(field_type)) => field_type.as_str(),
+        Some(_) => {
+            errors.push(format!("Field '{}' property 'type' must be a string", key));
+            return;
+        }
+        None => {
+            errors.push(format!("Field '{}' is missing required property 'type'", key));
+            return;
+        }
+    };
+
+    let Some(rule) = FIELD_RULES.iter().find(|r| r.types.contains(&field_type)) else {
+        errors.push(format!("Field '{}' has unknown type '{}'", key, field_type));
+        return;
+    };
+
+    for required in rule.required {
+        if !field.contains_key(*required) {
+            errors.push(format!("Field '{}' is missing required property '{}'", key, required));
+        }
+    }
+
+    let is_divider = field_type == "divider";
+    for (property, value) in field {
+        if property == "type" {
+            continue;
+        }
+
+        let kind = rule.properties.iter()
+            .chain(COMMON_PROPERTIES.iter().filter(|(p, _)| !is_divider || *p == "group" || *p == "label"))
+            .find(|(p, _)| p == property)
+            .map(|(_, kind)| *kind);
+
+        match kind {
+            Some(kind) if !matches_kind(value, kind) => {
+                errors.push(format!("Field '{}' property '{}' must be {}", key, property, kind.describe()));
+            }
+            None => errors.push(format!("Field '{}' has unknown property '{}' for type '{}'", key, property, field_type)),
+            _ => {}
+        }
+    }
+}
+
+/// Validates the raw content of a `fields.json` file, returns every problem found.
+pub fn validate(raw: &str) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    let fields: Value = match serde_json::from_str(raw) {
+        Ok(fields) => fields,
+        Err(err) => {
+            errors.push(format!("Invalid JSON at line {} column {}", err.line(), err.column()));
+            return errors;
+        }
+    };
+
+    let Some(fields) = fields.as_object() else {
+        errors.push(String::from("Root must be an object"));
+        return errors;
+    };
+
+    for (key, field) in fields {
+        if key == "$schema" {
+            if !field.is_string() {
+                errors.push(String::from("Property '$schema' must be a string"));
+            }
+
+            continue;
+        }
+
+        if key.starts_with('$') {
+            let Some((_, types)) = FILTER_FIELDS.iter().find(|(k, _)| k == key) else {
+                errors.push(format!("Field '{}' uses a reserved name", key));
+                continue;
+            };
+
+            let field_type = field.get("type").and_then(|t| t.as_str()).unwrap_or_default();
+            if !field_type.is_empty() && !types.contains(&field_type) {
+                errors.push(format!("Field '{}' must be of type {}", key, types.join(", ")));
+                continue;
+            }
+        }
+
+        validate_field(key, field, &mut errors);
+    }
+
+    return errors;
+}
+
+/// Checks a stored value against the field `value` rule, unknown field types accept anything.
+pub fn is_valid_value(field: &Value, value: &Value) -> bool {
+    let field_type = field.get("type").and_then(|t| t.as_str()).unwrap_or_default();
+    let Some(rule) = FIELD_RULES.iter().find(|r| r.types.contains(&field_type)) else {
+        return true;
+    };
+
+    return match rule.properties.iter().find(|(p, _)| *p == "value") {
+        Some((_, kind)) => matches_kind(value, *kind),
+        None => true
+    };
+}
+
+/* ================================================================================================================== */
+
+fn serialize_gallery(value: &Value) -> Value {
+    let Some(file_name) = value.as_str().filter(|v| !v.is_empty()) else {
+        return Value::Null;
+    };
+
+    if file_name.starts_with("/gallery/") {
+        return Value::String(String::from(file_name));
+    } else if file_name.contains('/') || file_name.contains('\\') || file_name == ".." {
+        return Value::Null;
+    }
+
+    return Value::String(format!("/gallery/{}", encode_uri_component(file_name)));
+}
+
+fn serialize_font(value: &Value) -> Value {
+    let family: String = value.as_str().unwrap_or_default().chars()
+        .filter(|c| !matches!(c, '"' | '\'' | '\\' | ';' | '{' | '}' | '<' | '>'))
+        .collect();
+
+    let family = family.trim();
+    if family.is_empty() {
+        return Value::String(String::from("inherit"));
+    }
+
+    return Value::String(format!("\"{}\"", family));
+}
+
+fn serialize_event_types(field: &Value, value: &Value) -> Value {
+    let allowed = field.get("options").and_then(|o| o.as_array());
+
+    let mut event_types: Vec<Value> = Vec::new();
+    for item in value.as_array().into_iter().flatten() {
+        let Some(event_type) = item.as_str() else {
+            continue;
+        };
+
+        if UNICHAT_EVENT_TYPES.contains(&event_type) && allowed.is_none_or(|a| a.contains(item)) && !event_types.contains(item) {
+            event_types.push(item.clone());
+        }
+    }
+
+    return Value::Array(event_types);
+}
+
+fn serialize_range(field: &Value, value: &Value) -> Value {
+    let min = field.get("min").cloned().unwrap_or(Value::from(0));
+    let max = field.get("max").cloned().unwrap_or(Value::from(0));
+
+    let (mut low, mut high) = match value {
+        Value::Object(obj) if obj.get("min").is_some_and(|v| v.is_number()) && obj.get("max").is_some_and(|v| v.is_number()) => (obj["min"].clone(), obj["max"].clone()),
+        _ => (min.clone(), max.clone())
+    };
+
+    let as_f64 = |v: &Value| v.as_f64().unwrap_or_default();
+    if as_f64(&low) > as_f64(&high) {
+        std::mem::swap(&mut low, &mut high);
+    }
+    if as_f64(&low) < as_f64(&min) {
+        low = min.clone();
+    }
+    if as_f64(&high) > as_f64(&max) {
+        high = max.clone();
+    }
+
+    return serde_json::json!({ "min": low, "max": high });
+}
+
+fn serialize_json(field: &Value, value: &Value) -> Value {
+    let Some(raw) = value.as_str() else {
+        return value.clone();
+    };
+
+    return match serde_json::from_str(raw) {
+        Ok(parsed) => parsed,
+        Err(_) => field.get("value").filter(|v| !v.is_string()).cloned().unwrap_or(Value::Null)
+    };
+}
+
+/// Converts a stored field value into what templates receive, e.g. gallery file names into `/gallery/` URLs.
+pub fn serialize_value(field: &Value, value: &Value) -> Value {
+    return match field.get("type").and_then(|t| t.as_str()) {
+        Some("gallery") => serialize_gallery(value),
+        Some("font") => serialize_font(value),
+        Some("eventtypes") => serialize_event_types(field, value),
+        Some("range") => serialize_range(field, value),
+        Some("json") => serialize_json(field, value),
+        _ => value.clone()
+    };
+}
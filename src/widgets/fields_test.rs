@@ -0,0 +1,134 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use serde_json::json;
+
+use crate::widgets::fields::serialize_value;
+use crate::widgets::fields::validate;
+
+#[test]
+fn test_validate_bundled_widgets() {
+    assert!(validate(include_str!("../../widgets/default/fields.json")).is_empty());
+    assert!(validate(include_str!("../../widgets/raid/fields.json")).is_empty());
+}
+
+#[test]
+fn test_validate_accepts_all_types() {
+    let raw = r##"{
+        "$schema": "./fields-schema.json",
+        "a": { "type": "text", "label": "A", "value": "x", "group": "G", "description": "D", "version": 2, "renamedFrom": ["z"] },
+        "b": { "type": "number", "label": "B", "value": 1, "min": 0, "max": 2.5, "step": 0.5 },
+        "c": { "type": "switch", "label": "C", "value": false },
+        "d": { "type": "colorpicker", "label": "D", "swatches": ["#FFF"] },
+        "e": { "type": "dropdown", "label": "E", "options": { "a": "A" } },
+        "f": { "type": "filepicker", "label": "F", "fileType": ["image", "audio"] },
+        "g": { "type": "divider" },
+        "h": { "type": "gallery", "label": "H", "value": "sound.mp3", "fileType": ["audio"] },
+        "i": { "type": "font", "label": "I", "value": "Roboto", "fonts": ["Roboto", "Arial"] },
+        "j": { "type": "eventtypes", "label": "J", "value": ["unichat:message"], "options": ["unichat:message", "unichat:donate"] },
+        "k": { "type": "range", "label": "K", "value": { "min": 10, "max": 20 }, "min": 0, "max": 100, "step": 5 },
+        "l": { "type": "json", "label": "L", "value": { "a": [1, 2] } }
+    }"##;
+
+    assert_eq!(validate(raw), Vec::<String>::new());
+}
+
+#[test]
+fn test_validate_reports_invalid_json() {
+    let errors = validate("{\n  \"a\": }");
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("line 2"));
+
+    assert_eq!(validate("[]"), vec![String::from("Root must be an object")]);
+}
+
+#[test]
+fn test_validate_reports_field_problems() {
+    let raw = r##"{
+        "a": { "label": "A" },
+        "b": { "type": "slider", "label": "B" },
+        "c": { "type": "text" },
+        "d": { "type": "number", "label": "D", "value": "10" },
+        "e": { "type": "dropdown", "label": "E" },
+        "f": { "type": "filepicker", "label": "F", "fileType": ["image", "image"] },
+        "g": { "type": "divider", "description": "G" },
+        "h": "text"
+    }"##;
+
+    assert_eq!(validate(raw), vec![
+        String::from("Field 'a' is missing required property 'type'"),
+        String::from("Field 'b' has unknown type 'slider'"),
+        String::from("Field 'c' is missing required property 'label'"),
+        String::from("Field 'd' property 'value' must be a number"),
+        String::from("Field 'e' is missing required property 'options'"),
+        String::from("Field 'f' property 'fileType' must be a non-empty array of unique file types"),
+        String::from("Field 'g' has unknown property 'description' for type 'divider'"),
+        String::from("Field 'h' must be an object")
+    ]);
+}
+
+#[test]
+fn test_validate_reports_new_field_problems() {
+    let raw = r##"{
+        "a": { "type": "eventtypes", "label": "A", "value": ["unichat:nope"] },
+        "b": { "type": "range", "label": "B", "value": { "min": 1 }, "min": 0 }
+    }"##;
+
+    assert_eq!(validate(raw), vec![
+        String::from("Field 'a' property 'value' must be an array of unique event types"),
+        String::from("Field 'b' is missing required property 'max'"),
+        String::from("Field 'b' property 'value' must be an object with numeric `min` and `max`")
+    ]);
+}
+
+#[test]
+fn test_validate_reserved_fields() {
+    let raw = r##"{
+        "$filterEventTypes": { "type": "eventtypes", "label": "Events", "value": ["unichat:message"] },
+        "$filterMinDonation": { "type": "text", "label": "Minimum" },
+        "$other": { "type": "text", "label": "Other" }
+    }"##;
+
+    assert_eq!(validate(raw), vec![
+        String::from("Field '$filterMinDonation' must be of type number"),
+        String::from("Field '$other' uses a reserved name")
+    ]);
+}
+
+#[test]
+fn test_serialize_value() {
+    let gallery = json!({ "type": "gallery", "label": "G" });
+    assert_eq!(serialize_value(&gallery, &json!("my sound.mp3")), json!("/gallery/my%20sound.mp3"));
+    assert_eq!(serialize_value(&gallery, &json!("/gallery/a.png")), json!("/gallery/a.png"));
+    assert_eq!(serialize_value(&gallery, &json!("../secret.txt")), json!(null));
+    assert_eq!(serialize_value(&gallery, &json!("")), json!(null));
+
+    let font = json!({ "type": "font", "label": "F" });
+    assert_eq!(serialize_value(&font, &json!("Open Sans")), json!("\"Open Sans\""));
+    assert_eq!(serialize_value(&font, &json!("a\"; } body { x")), json!("\"a  body  x\""));
+    assert_eq!(serialize_value(&font, &json!(null)), json!("inherit"));
+
+    let event_types = json!({ "type": "eventtypes", "label": "E", "options": ["unichat:message", "unichat:donate"] });
+    assert_eq!(serialize_value(&event_types, &json!(["unichat:donate", "unichat:raid", "x", "unichat:donate"])), json!(["unichat:donate"]));
+    assert_eq!(serialize_value(&event_types, &json!(null)), json!([]));
+
+    let range = json!({ "type": "range", "label": "R", "min": 0, "max": 100 });
+    assert_eq!(serialize_value(&range, &json!({ "min": 30, "max": 10 })), json!({ "min": 10, "max": 30 }));
+    assert_eq!(serialize_value(&range, &json!({ "min": -5, "max": 150.5 })), json!({ "min": 0, "max": 100 }));
+    assert_eq!(serialize_value(&range, &json!(null)), json!({ "min": 0, "max": 100 }));
+
+    let json_field = json!({ "type": "json", "label": "J", "value": { "a": 1 } });
+    assert_eq!(serialize_value(&json_field, &json!("{\"b\": [1, 2]}")), json!({ "b": [1, 2] }));
+    assert_eq!(serialize_value(&json_field, &json!("{ invalid")), json!({ "a": 1 }));
+    assert_eq!(serialize_value(&json_field, &json!([1])), json!([1]));
+
+    let text = json!({ "type": "text", "label": "T" });
+    assert_eq!(serialize_value(&text, &json!("<b>")), json!("<b>"));
+}
@@ -0,0 +1,104 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use crate::events::unichat::UniChatEvent;
+
+pub const FILTER_EVENT_TYPES_KEY: &str = "$filterEventTypes";
+pub const FILTER_PLATFORMS_KEY: &str = "$filterPlatforms";
+pub const FILTER_MIN_DONATION_KEY: &str = "$filterMinDonation";
+pub const FILTER_IGNORED_AUTHORS_KEY: &str = "$filterIgnoredAuthors";
+
+/// Reserved `fields.json` keys applied by the WebSocket layer, with the field types each one accepts.
+pub const FILTER_FIELDS: [(&str, &[&str]); 4] = [
+    (FILTER_EVENT_TYPES_KEY, &["eventtypes"]),
+    (FILTER_PLATFORMS_KEY, &["text", "textarea", "json"]),
+    (FILTER_MIN_DONATION_KEY, &["number"]),
+    (FILTER_IGNORED_AUTHORS_KEY, &["text", "textarea", "json"])
+];
+
+/// Events that keep widgets consistent, they are never filtered out.
+const CONTROL_EVENT_TYPES: [&str; 4] = ["unichat:clear", "unichat:remove_message", "unichat:remove_author", "unichat:userstore_update"];
+
+#[derive(Clone, Debug, Default)]
+pub struct WidgetEventFilter {
+    event_types: Option<HashSet<String>>,
+    platforms: Option<HashSet<String>>,
+    min_donation_value: Option<f64>,
+    ignored_authors: HashSet<String>
+}
+
+/// Accepts a JSON array of strings or a comma/line separated string.
+fn to_list(value: Option<&Value>) -> HashSet<String> {
+    let items: Vec<String> = match value {
+        Some(Value::Array(items)) => items.iter().filter_map(|i| i.as_str().map(String::from)).collect(),
+        Some(Value::String(raw)) => raw.split([',', '\n']).map(String::from).collect(),
+        _ => Vec::new()
+    };
+
+    return items.iter().map(|i| i.trim().to_lowercase()).filter(|i| !i.is_empty()).collect();
+}
+
+impl WidgetEventFilter {
+    /// Builds the filter from the widget template variables, `None` when no filter is configured.
+    pub fn from_variables(variables: &HashMap<String, Value>) -> Option<Self> {
+        let event_types = to_list(variables.get(FILTER_EVENT_TYPES_KEY));
+        let platforms = to_list(variables.get(FILTER_PLATFORMS_KEY));
+        let min_donation_value = variables.get(FILTER_MIN_DONATION_KEY).and_then(|v| v.as_f64()).filter(|v| *v > 0.0);
+        let ignored_authors = to_list(variables.get(FILTER_IGNORED_AUTHORS_KEY));
+
+        if event_types.is_empty() && platforms.is_empty() && min_donation_value.is_none() && ignored_authors.is_empty() {
+            return None;
+        }
+
+        return Some(Self {
+            event_types: Some(event_types).filter(|t| !t.is_empty()),
+            platforms: Some(platforms).filter(|p| !p.is_empty()),
+            min_donation_value: min_donation_value,
+            ignored_authors: ignored_authors
+        });
+    }
+
+    pub fn matches(&self, event: &UniChatEvent) -> bool {
+        let event_type = event.event_type();
+        if CONTROL_EVENT_TYPES.contains(&event_type) {
+            return true;
+        }
+
+        if self.event_types.as_ref().is_some_and(|types| !types.contains(event_type)) {
+            return false;
+        }
+
+        if let (Some(platforms), Some(platform)) = (&self.platforms, event.platform()) {
+            if !platforms.contains(&platform.as_str().to_lowercase()) {
+                return false;
+            }
+        }
+
+        if let (Some(min_donation_value), UniChatEvent::Donate(payload)) = (self.min_donation_value, event) {
+            if payload.value < min_donation_value {
+                return false;
+            }
+        }
+
+        if let Some((author_id, author_username)) = event.author() {
+            let is_ignored = |author: &str| self.ignored_authors.contains(&author.to_lowercase());
+            if is_ignored(author_id) || author_username.is_some_and(is_ignored) {
+                return false;
+            }
+        }
+
+        return true;
+    }
+}
@@ -0,0 +1,84 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::collections::HashMap;
+
+use serde_json::json;
+use serde_json::Value;
+
+use crate::events::unichat::UniChatEvent;
+use crate::widgets::filter::WidgetEventFilter;
+
+fn variables(value: Value) -> HashMap<String, Value> {
+    return serde_json::from_value(value).unwrap();
+}
+
+fn donate(platform: &str, author_username: &str, value: f64) -> UniChatEvent {
+    return serde_json::from_value(json!({
+        "type": "unichat:donate",
+        "data": {
+            "channelId": "channel", "channelName": null, "platform": platform, "flags": {},
+            "authorId": format!("{}-id", author_username), "authorUsername": author_username, "authorDisplayName": author_username,
+            "authorDisplayColor": "#FFFFFF", "authorProfilePictureUrl": null, "authorBadges": [], "authorType": "VIEWER",
+            "value": value, "currency": "USD", "originalValue": null, "originalCurrency": null,
+            "messageId": "message", "messageText": null, "emotes": [], "timestamp": 0
+        }
+    })).unwrap();
+}
+
+fn message(platform: &str, author_username: &str) -> UniChatEvent {
+    return serde_json::from_value(json!({
+        "type": "unichat:message",
+        "data": {
+            "channelId": "channel", "channelName": null, "platform": platform, "flags": {},
+            "authorId": format!("{}-id", author_username), "authorUsername": author_username, "authorDisplayName": author_username,
+            "authorDisplayColor": "#FFFFFF", "authorProfilePictureUrl": null, "authorBadges": [], "authorType": "VIEWER",
+            "messageId": "message", "messageText": "Hello", "emotes": [], "timestamp": 0
+        }
+    })).unwrap();
+}
+
+fn clear() -> UniChatEvent {
+    return serde_json::from_value(json!({ "type": "unichat:clear", "data": { "platform": null, "timestamp": 0 } })).unwrap();
+}
+
+#[test]
+fn test_no_filter_configured() {
+    assert!(WidgetEventFilter::from_variables(&variables(json!({ "title": "Hello" }))).is_none());
+    assert!(WidgetEventFilter::from_variables(&variables(json!({ "$filterEventTypes": [], "$filterPlatforms": "", "$filterMinDonation": 0 }))).is_none());
+}
+
+#[test]
+fn test_filter_event_types_and_platforms() {
+    let filter = WidgetEventFilter::from_variables(&variables(json!({
+        "$filterEventTypes": ["unichat:message"],
+        "$filterPlatforms": "Twitch, youtube"
+    }))).unwrap();
+
+    assert!(filter.matches(&message("twitch", "viewer")));
+    assert!(filter.matches(&message("youtube", "viewer")));
+    assert!(!filter.matches(&message("kick", "viewer")));
+    assert!(!filter.matches(&donate("twitch", "viewer", 10.0)));
+    assert!(filter.matches(&clear()));
+}
+
+#[test]
+fn test_filter_donations_and_authors() {
+    let filter = WidgetEventFilter::from_variables(&variables(json!({
+        "$filterMinDonation": 5,
+        "$filterIgnoredAuthors": "Nightbot\nstreamelements"
+    }))).unwrap();
+
+    assert!(filter.matches(&donate("twitch", "viewer", 5.0)));
+    assert!(!filter.matches(&donate("twitch", "viewer", 4.99)));
+    assert!(filter.matches(&message("twitch", "viewer")));
+    assert!(!filter.matches(&message("twitch", "nightbot")));
+    assert!(!filter.matches(&message("youtube", "StreamElements")));
+}
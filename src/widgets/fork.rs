@@ -0,0 +1,158 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Error;
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::widgets::archive::list_widget_files;
+use crate::widgets::manifest;
+
+pub const FORK_FILE: &str = "fork.json";
+
+/// Stored as `fork.json` inside the forked widget, `files` holds the source file hashes at fork time.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetForkRecord {
+    pub source: String,
+    pub source_version: Option<String>,
+    pub forked_at: i64,
+    pub files: IndexMap<String, String>
+}
+
+#[derive(Serialize, Clone, Debug, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetForkStatus {
+    pub source: String,
+    pub source_version: Option<String>,
+    pub forked_at: i64,
+    pub source_missing: bool,
+    pub source_changed: bool
+}
+
+#[derive(Serialize, Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WidgetFileChange {
+    Unchanged,
+    Added,
+    Modified,
+    Removed
+}
+
+#[derive(Serialize, Clone, Debug, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetForkChange {
+    pub path: String,
+    pub source: WidgetFileChange,
+    pub fork: WidgetFileChange
+}
+
+/// FNV-1a, stable across builds so records written by older versions remain comparable.
+fn hash_content(content: &[u8]) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in content {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+
+    return format!("{:016x}", hash);
+}
+
+pub fn hash_widget_files(widget_path: &Path) -> Result<IndexMap<String, String>, Error> {
+    let mut hashes = IndexMap::new();
+    for (relative_path, path) in list_widget_files(widget_path, false)? {
+        if relative_path == FORK_FILE {
+            continue;
+        }
+
+        // Hash the manifest as it is written to the fork, otherwise resolved placeholders read as changes.
+        let mut exported = None;
+        if relative_path == manifest::MANIFEST_FILE {
+            exported = manifest::exported_manifest(widget_path).ok().flatten();
+        }
+
+        let content = match exported {
+            Some(content) => content.into_bytes(),
+            None => fs::read(&path)?
+        };
+
+        hashes.insert(relative_path, hash_content(&content));
+    }
+
+    return Ok(hashes);
+}
+
+fn compare(recorded: Option<&String>, current: Option<&String>) -> WidgetFileChange {
+    return match (recorded, current) {
+        (Some(recorded), Some(current)) if recorded == current => WidgetFileChange::Unchanged,
+        (Some(_), Some(_)) => WidgetFileChange::Modified,
+        (None, Some(_)) => WidgetFileChange::Added,
+        (Some(_), None) => WidgetFileChange::Removed,
+        (None, None) => WidgetFileChange::Unchanged
+    };
+}
+
+/* ================================================================================================================== */
+
+pub fn load_record(widget_path: &Path) -> Option<WidgetForkRecord> {
+    let raw = fs::read_to_string(widget_path.join(FORK_FILE)).ok()?;
+
+    return match serde_json::from_str(&raw) {
+        Ok(record) => Some(record),
+        Err(err) => {
+            log::error!("Failed to parse fork record for widget '{:?}': {:#?}", widget_path, err);
+            return None;
+        }
+    };
+}
+
+pub fn write_record(widget_path: &Path, record: &WidgetForkRecord) -> Result<(), Error> {
+    fs::write(widget_path.join(FORK_FILE), serde_json::to_string_pretty(record)?)?;
+    return Ok(());
+}
+
+/// `source_path` is `None` when the source widget is no longer loaded.
+pub fn get_status(record: &WidgetForkRecord, source_path: Option<&Path>) -> WidgetForkStatus {
+    let source_changed = source_path.is_some_and(|path| hash_widget_files(path).is_ok_and(|hashes| hashes != record.files));
+
+    return WidgetForkStatus {
+        source: record.source.clone(),
+        source_version: record.source_version.clone(),
+        forked_at: record.forked_at,
+        source_missing: source_path.is_none(),
+        source_changed: source_changed
+    };
+}
+
+/// Lists every file changed since the fork, either on the source widget or on the fork itself.
+pub fn get_changes(record: &WidgetForkRecord, source_path: &Path, fork_path: &Path) -> Result<Vec<WidgetForkChange>, Error> {
+    let source_files = hash_widget_files(source_path)?;
+    let fork_files = hash_widget_files(fork_path)?;
+
+    let paths: BTreeSet<&String> = record.files.keys().chain(source_files.keys()).chain(fork_files.keys()).collect();
+
+    let mut changes = Vec::new();
+    for path in paths {
+        let recorded = record.files.get(path);
+        let source = compare(recorded, source_files.get(path));
+        let fork = compare(recorded, fork_files.get(path));
+
+        if source != WidgetFileChange::Unchanged || fork != WidgetFileChange::Unchanged {
+            changes.push(WidgetForkChange { path: path.clone(), source: source, fork: fork });
+        }
+    }
+
+    return Ok(changes);
+}
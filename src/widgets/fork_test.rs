@@ -0,0 +1,92 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::utils::test_utils::temp_dir;
+use crate::widgets::fork::WidgetFileChange;
+use crate::widgets::fork::WidgetForkChange;
+use crate::widgets::fork::WidgetForkRecord;
+use crate::widgets::fork::get_changes;
+use crate::widgets::fork::get_status;
+use crate::widgets::fork::hash_widget_files;
+
+fn create_widget(name: &str) -> PathBuf {
+    let widget_path = temp_dir(&format!("fork-{}", name));
+    fs::write(widget_path.join("widget.html"), "<div></div>").unwrap();
+    fs::write(widget_path.join("widget.css"), "div {}").unwrap();
+    fs::write(widget_path.join("fieldstate.json"), "{}").unwrap();
+
+    return widget_path;
+}
+
+fn create_record(source_path: &Path) -> WidgetForkRecord {
+    return WidgetForkRecord {
+        source: String::from("default"),
+        source_version: Some(String::from("1.5.0")),
+        forked_at: 0,
+        files: hash_widget_files(source_path).unwrap()
+    };
+}
+
+#[test]
+fn test_hash_widget_files() {
+    let widget_path = create_widget("hash");
+
+    let hashes = hash_widget_files(&widget_path).unwrap();
+    assert_eq!(hashes.keys().cloned().collect::<Vec<String>>(), vec!["widget.css", "widget.html"]);
+    assert_eq!(hashes.get("widget.html").unwrap(), "92b594ed31fef256");
+
+    let _ = fs::remove_dir_all(&widget_path);
+}
+
+#[test]
+fn test_status() {
+    let source_path = create_widget("status");
+    let record = create_record(&source_path);
+
+    let status = get_status(&record, Some(&source_path));
+    assert!(!status.source_changed && !status.source_missing);
+
+    fs::write(source_path.join("fieldstate.json"), r#"{ "a": 1 }"#).unwrap();
+    assert!(!get_status(&record, Some(&source_path)).source_changed);
+
+    fs::write(source_path.join("widget.js"), "").unwrap();
+    assert!(get_status(&record, Some(&source_path)).source_changed);
+
+    assert!(get_status(&record, None).source_missing);
+
+    let _ = fs::remove_dir_all(&source_path);
+}
+
+#[test]
+fn test_changes() {
+    let source_path = create_widget("changes-source");
+    let fork_path = create_widget("changes-fork");
+    let record = create_record(&source_path);
+
+    assert!(get_changes(&record, &source_path, &fork_path).unwrap().is_empty());
+
+    fs::write(source_path.join("widget.html"), "<div>updated</div>").unwrap();
+    fs::write(fork_path.join("widget.css"), "div { color: red; }").unwrap();
+    fs::remove_file(source_path.join("widget.css")).unwrap();
+    fs::write(fork_path.join("widget.js"), "").unwrap();
+
+    assert_eq!(get_changes(&record, &source_path, &fork_path).unwrap(), vec![
+        WidgetForkChange { path: String::from("widget.css"), source: WidgetFileChange::Removed, fork: WidgetFileChange::Modified },
+        WidgetForkChange { path: String::from("widget.html"), source: WidgetFileChange::Modified, fork: WidgetFileChange::Unchanged },
+        WidgetForkChange { path: String::from("widget.js"), source: WidgetFileChange::Unchanged, fork: WidgetFileChange::Added }
+    ]);
+
+    let _ = fs::remove_dir_all(&source_path);
+    let _ = fs::remove_dir_all(&fork_path);
+}
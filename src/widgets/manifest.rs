@@ -0,0 +1,127 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use anyhow::Error;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::UNICHAT_AUTHORS;
+use crate::UNICHAT_HOMEPAGE;
+use crate::UNICHAT_LICENSE_CODE;
+use crate::UNICHAT_VERSION;
+use crate::utils::properties;
+use crate::utils::properties::AppPaths;
+use crate::utils::safe_guard_path;
+use crate::utils::semver;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WidgetManifestYAML {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub version: Option<String>,
+    pub author: Option<String>,
+    pub license: Option<String>,
+    pub homepage: Option<String>,
+    pub preview: Option<String>,
+    pub unichat: Option<String>
+}
+
+impl WidgetManifestYAML {
+    /// Checks the required UniChat version, returns `None` when no requirement is declared.
+    pub fn is_compatible(&self) -> Result<Option<bool>, Error> {
+        let Some(unichat) = &self.unichat else {
+            return Ok(None);
+        };
+
+        let version_req = semver::VersionRange::parse(unichat)?;
+        let unichat_version = semver::Version::parse(UNICHAT_VERSION)?;
+
+        return Ok(Some(version_req.matches(&unichat_version)));
+    }
+
+    /// Preview image path relative to the widget `assets` folder, a leading `/` is accepted.
+    pub fn preview_asset(&self) -> Option<&str> {
+        return self.preview.as_deref().map(|preview| preview.trim_start_matches('/'));
+    }
+}
+
+pub const MANIFEST_FILE: &str = "widget.yaml";
+
+pub fn manifest_path(widget_path: &Path) -> PathBuf {
+    return widget_path.join(MANIFEST_FILE);
+}
+
+pub fn load_manifest(widget_path: &Path) -> Result<Option<WidgetManifestYAML>, Error> {
+    let manifest_path = manifest_path(widget_path);
+    if !manifest_path.is_file() {
+        return Ok(None);
+    }
+
+    let manifest_content = fs::read_to_string(&manifest_path)?;
+    let mut manifest: WidgetManifestYAML = serde_saphyr::from_str(&manifest_content)?;
+
+    if widget_path.starts_with(properties::get_app_path(AppPaths::UniChatSystemWidgets)) {
+        if manifest.version.as_deref().is_some_and(|v| v == "${unichat_version}") {
+            manifest.version = Some(String::from(UNICHAT_VERSION));
+        }
+
+        if manifest.author.as_deref().is_some_and(|a| a == "${unichat_authors}") {
+            manifest.author = Some(String::from(UNICHAT_AUTHORS));
+        }
+
+        if manifest.license.as_deref().is_some_and(|l| l == "${unichat_license}") {
+            manifest.license = Some(String::from(UNICHAT_LICENSE_CODE));
+        }
+
+        if manifest.homepage.as_deref().is_some_and(|h| h == "${unichat_homepage}") {
+            manifest.homepage = Some(String::from(UNICHAT_HOMEPAGE));
+        }
+    }
+
+    if let Some(version) = &manifest.version {
+        if let Err(err) = semver::Version::parse(version) {
+            return Err(anyhow!("Invalid version '{}': {:?}", version, err));
+        }
+    }
+
+    if let Some(unichat) = &manifest.unichat {
+        if let Err(err) = semver::VersionRange::parse(unichat) {
+            return Err(anyhow!("Invalid UniChat version range '{}': {:?}", unichat, err));
+        }
+    }
+
+    if let Some(preview) = manifest.preview_asset() {
+        let preview_path = safe_guard_path(&widget_path.join("assets"), preview)?;
+        if !preview_path.is_file() {
+            return Err(anyhow!("Preview image '{}' was not found in widget assets", preview));
+        }
+    }
+
+    return Ok(Some(manifest));
+}
+
+/// The manifest as written to exports and forks. System manifests hold placeholders that are only resolved
+/// for system widgets, so they are written resolved. `None` means the file is copied as is.
+pub fn exported_manifest(widget_path: &Path) -> Result<Option<String>, Error> {
+    if !widget_path.starts_with(properties::get_app_path(AppPaths::UniChatSystemWidgets)) {
+        return Ok(None);
+    }
+
+    let Some(manifest) = load_manifest(widget_path)? else {
+        return Ok(None);
+    };
+
+    return Ok(Some(serde_saphyr::to_string(&manifest)?));
+}
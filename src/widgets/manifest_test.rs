@@ -0,0 +1,30 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::fs;
+
+use crate::utils::test_utils::temp_dir;
+use crate::widgets::manifest::load_manifest;
+
+#[test]
+fn test_preview_with_leading_slash() {
+    let widget_path = temp_dir("manifest");
+    fs::create_dir_all(widget_path.join("assets")).unwrap();
+    fs::write(widget_path.join("assets").join("preview.png"), [0u8]).unwrap();
+
+    fs::write(widget_path.join("widget.yaml"), "preview: /preview.png\n").unwrap();
+    let manifest = load_manifest(&widget_path).unwrap().unwrap();
+    assert_eq!(manifest.preview_asset(), Some("preview.png"));
+
+    fs::write(widget_path.join("widget.yaml"), "preview: missing.png\n").unwrap();
+    assert!(load_manifest(&widget_path).is_err());
+
+    let _ = fs::remove_dir_all(&widget_path);
+}
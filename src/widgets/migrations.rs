@@ -0,0 +1,151 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Error;
+use indexmap::IndexMap;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::widgets::fields;
+
+/// Reserved fieldstate key holding the `version` of each field when its value was last migrated.
+pub const FIELD_VERSIONS_KEY: &str = "$fieldVersions";
+
+fn renamed_from(field: &Value) -> Vec<String> {
+    return match field.get("renamedFrom") {
+        Some(Value::String(old_key)) => vec![old_key.clone()],
+        Some(Value::Array(old_keys)) => old_keys.iter().filter_map(|k| k.as_str().map(String::from)).collect(),
+        _ => Vec::new()
+    };
+}
+
+/// Tries to keep a value whose field type changed, e.g. `"12"` for a `number` field.
+fn coerce(field: &Value, value: &Value) -> Option<Value> {
+    if fields::is_valid_value(field, value) {
+        return Some(value.clone());
+    }
+
+    let candidate = match value {
+        Value::String(s) => {
+            if let Ok(number) = s.trim().parse::<i64>() {
+                Value::from(number)
+            } else if let Ok(number) = s.trim().parse::<f64>() {
+                Value::from(number)
+            } else {
+                match s.trim() {
+                    "true" => Value::Bool(true),
+                    "false" => Value::Bool(false),
+                    _ => return None
+                }
+            }
+        }
+        Value::Number(number) => Value::String(number.to_string()),
+        Value::Bool(b) => Value::String(b.to_string()),
+        _ => return None
+    };
+
+    return Some(candidate).filter(|c| fields::is_valid_value(field, c));
+}
+
+/// Applies `renamedFrom` and `version` changes to a fieldstate, returns the list of applied changes.
+pub fn migrate(fields: &IndexMap<String, Value>, fieldstate: &mut Map<String, Value>) -> Vec<String> {
+    let mut changes = Vec::new();
+    let mut versions = fieldstate.get(FIELD_VERSIONS_KEY).and_then(|v| v.as_object()).cloned().unwrap_or_default();
+
+    for (key, field) in fields {
+        if !field.is_object() {
+            continue;
+        }
+
+        if !fieldstate.contains_key(key) {
+            for old_key in renamed_from(field) {
+                if let Some(value) = fieldstate.remove(&old_key) {
+                    if let Some(version) = versions.remove(&old_key) {
+                        versions.insert(key.clone(), version);
+                    }
+
+                    fieldstate.insert(key.clone(), value);
+                    changes.push(format!("Renamed field '{}' to '{}'", old_key, key));
+                    break;
+                }
+            }
+        }
+
+        let Some(version) = field.get("version").and_then(|v| v.as_u64()) else {
+            continue;
+        };
+
+        let stored_version = versions.get(key).and_then(|v| v.as_u64()).unwrap_or(1);
+        if stored_version == version {
+            continue;
+        }
+
+        // Fields without a stored value already use the new defaults.
+        let Some(value) = fieldstate.get(key) else {
+            continue;
+        };
+
+        match coerce(field, value) {
+            Some(coerced) if &coerced == value => {
+                changes.push(format!("Updated field '{}' from version {} to {}", key, stored_version, version));
+            }
+            Some(coerced) => {
+                fieldstate.insert(key.clone(), coerced);
+                changes.push(format!("Converted field '{}' value from version {} to {}", key, stored_version, version));
+            }
+            None => {
+                fieldstate.remove(key);
+                changes.push(format!("Reset field '{}' value incompatible with version {}", key, version));
+            }
+        }
+
+        versions.insert(key.clone(), Value::from(version));
+    }
+
+    if !changes.is_empty() {
+        fieldstate.insert(String::from(FIELD_VERSIONS_KEY), Value::Object(versions));
+    }
+
+    return changes;
+}
+
+fn backup_path(fieldstate_path: &Path) -> PathBuf {
+    let mut file_name = fieldstate_path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".bak");
+
+    return fieldstate_path.with_file_name(file_name);
+}
+
+/// Migrates a fieldstate file in place, the content before the first migration is kept as `<file>.bak`.
+pub fn migrate_file(fields: &IndexMap<String, Value>, fieldstate_path: &Path) -> Result<Vec<String>, Error> {
+    if !fieldstate_path.is_file() {
+        return Ok(Vec::new());
+    }
+
+    let raw = fs::read_to_string(fieldstate_path)?;
+    let mut fieldstate: Map<String, Value> = serde_json::from_str(&raw)?;
+
+    let changes = migrate(fields, &mut fieldstate);
+    if !changes.is_empty() {
+        // Later migrations keep the existing backup, it holds the original user fieldstate.
+        let backup_path = backup_path(fieldstate_path);
+        if !backup_path.exists() {
+            fs::write(backup_path, raw)?;
+        }
+
+        fs::write(fieldstate_path, serde_json::to_string_pretty(&fieldstate)?)?;
+    }
+
+    return Ok(changes);
+}
@@ -0,0 +1,92 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::fs;
+
+use indexmap::IndexMap;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::utils::test_utils::temp_dir;
+use crate::widgets::migrations::migrate;
+use crate::widgets::migrations::migrate_file;
+
+fn to_map<T: serde::de::DeserializeOwned>(value: Value) -> T {
+    return serde_json::from_value(value).unwrap();
+}
+
+#[test]
+fn test_migrate_renamed_fields() {
+    let fields: IndexMap<String, Value> = to_map(json!({
+        "title": { "type": "text", "label": "Title", "renamedFrom": ["header", "caption"] },
+        "size": { "type": "number", "label": "Size" }
+    }));
+
+    let mut fieldstate: Map<String, Value> = to_map(json!({ "caption": "Hello", "size": 10 }));
+    let changes = migrate(&fields, &mut fieldstate);
+
+    assert_eq!(changes, vec![String::from("Renamed field 'caption' to 'title'")]);
+    assert_eq!(Value::Object(fieldstate), json!({ "size": 10, "title": "Hello", "$fieldVersions": {} }));
+}
+
+#[test]
+fn test_migrate_keeps_current_key() {
+    let fields: IndexMap<String, Value> = to_map(json!({ "title": { "type": "text", "label": "Title", "renamedFrom": "header" } }));
+
+    let mut fieldstate: Map<String, Value> = to_map(json!({ "title": "New", "header": "Old" }));
+    assert!(migrate(&fields, &mut fieldstate).is_empty());
+    assert_eq!(fieldstate.get("title"), Some(&json!("New")));
+}
+
+#[test]
+fn test_migrate_retyped_fields() {
+    let fields: IndexMap<String, Value> = to_map(json!({
+        "size": { "type": "number", "label": "Size", "version": 2 },
+        "enabled": { "type": "switch", "label": "Enabled", "version": 2 },
+        "color": { "type": "colorpicker", "label": "Color", "version": 2 },
+        "range": { "type": "range", "label": "Range", "min": 0, "max": 10, "version": 2 }
+    }));
+
+    let mut fieldstate: Map<String, Value> = to_map(json!({ "size": "12", "enabled": "true", "color": "#FFF", "range": 5 }));
+    let changes = migrate(&fields, &mut fieldstate);
+    assert_eq!(changes.len(), 4);
+    assert_eq!(Value::Object(fieldstate.clone()), json!({
+        "size": 12,
+        "enabled": true,
+        "color": "#FFF",
+        "$fieldVersions": { "size": 2, "enabled": 2, "color": 2, "range": 2 }
+    }));
+
+    assert!(migrate(&fields, &mut fieldstate).is_empty());
+}
+
+#[test]
+fn test_migrate_file_keeps_backup() {
+    let dir = temp_dir("migrations");
+
+    let fields: IndexMap<String, Value> = to_map(json!({ "title": { "type": "text", "label": "Title", "renamedFrom": "header" } }));
+    let fieldstate_path = dir.join("fieldstate.json");
+    fs::write(&fieldstate_path, r#"{"header":"Hello"}"#).unwrap();
+
+    assert_eq!(migrate_file(&fields, &fieldstate_path).unwrap().len(), 1);
+    assert_eq!(fs::read_to_string(dir.join("fieldstate.json.bak")).unwrap(), r#"{"header":"Hello"}"#);
+
+    let migrated: Value = serde_json::from_str(&fs::read_to_string(&fieldstate_path).unwrap()).unwrap();
+    assert_eq!(migrated.get("title"), Some(&json!("Hello")));
+
+    let fields: IndexMap<String, Value> = to_map(json!({ "heading": { "type": "text", "label": "Heading", "renamedFrom": "title" } }));
+    assert_eq!(migrate_file(&fields, &fieldstate_path).unwrap().len(), 1);
+    assert_eq!(fs::read_to_string(dir.join("fieldstate.json.bak")).unwrap(), r#"{"header":"Hello"}"#);
+
+    assert!(migrate_file(&fields, &dir.join("missing.json")).unwrap().is_empty());
+
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -21,10 +21,33 @@ use anyhow::Error;
 use indexmap::IndexMap;
 use serde::Serialize;
 
+use crate::UNICHAT_DISPLAY_NAME;
+use crate::UNICHAT_VERSION;
 use crate::plugins::UniChatPlugin;
 use crate::utils::constants::BASE_REST_PORT;
 use crate::utils::properties;
 use crate::utils::properties::AppPaths;
+use crate::widgets::fork::WidgetForkStatus;
+use crate::widgets::manifest::WidgetManifestYAML;
+use crate::widgets::template::TemplateContext;
+
+#[cfg(test)] mod archive_test;
+pub mod archive;
+#[cfg(test)] mod fields_test;
+pub mod fields;
+#[cfg(test)] mod filter_test;
+pub mod filter;
+#[cfg(test)] mod fork_test;
+pub mod fork;
+#[cfg(test)] mod manifest_test;
+pub mod manifest;
+#[cfg(test)] mod migrations_test;
+pub mod migrations;
+#[cfg(test)] mod preset_test;
+pub mod preset;
+#[cfg(test)] mod template_test;
+pub mod template;
+#[cfg(test)] mod widgets_test;
 
 #[derive(Serialize, Clone, Debug, Eq, PartialEq)]
 #[serde(tag = "type", content = "value", rename_all = "SCREAMING_SNAKE_CASE")]
@@ -42,6 +65,9 @@ pub struct WidgetMetadata {
     path: PathBuf,
     pub rest_path: String,
     pub widget_source: WidgetSource,
+    pub manifest: Option<WidgetManifestYAML>,
+    pub preview_url: Option<String>,
+    pub fork: Option<WidgetForkStatus>,
     pub warnings: Vec<String>
 }
 
@@ -80,17 +106,61 @@ impl WidgetMetadata {
 
     /* ====================================================================== */
 
-    pub fn fieldstate_path(&self) -> PathBuf {
-        return self.path.join("fieldstate.json");
+    pub fn instances_path(&self) -> PathBuf {
+        return self.path.join("instances");
     }
 
-    pub fn fieldstate(&self) -> HashMap<String, serde_json::Value> {
-        let raw = fs::read_to_string(self.fieldstate_path()).unwrap_or(String::from("{}"));
+    pub fn instances(&self) -> Vec<String> {
+        let mut instances: Vec<String> = Vec::new();
+
+        if let Ok(entries) = fs::read_dir(self.instances_path()) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if !path.is_file() || path.extension().is_none_or(|ext| ext != "json") {
+                    continue;
+                }
+
+                let instance_name = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+                if is_valid_instance_name(&instance_name) {
+                    instances.push(instance_name);
+                }
+            }
+        }
+
+        instances.sort();
+        return instances;
+    }
+
+    /* ====================================================================== */
+
+    /// Returns the fieldstate file of the given instance, or the widget main `fieldstate.json` when `None`.
+    pub fn fieldstate_path(&self, instance: Option<&str>) -> Result<PathBuf, Error> {
+        let Some(instance) = instance else {
+            return Ok(self.path.join("fieldstate.json"));
+        };
+
+        if !is_valid_instance_name(instance) {
+            return Err(anyhow!("Invalid instance name '{}'", instance));
+        }
+
+        return Ok(self.instances_path().join(format!("{}.json", instance)));
+    }
+
+    pub fn fieldstate(&self, instance: Option<&str>) -> HashMap<String, serde_json::Value> {
+        let fieldstate_path = match self.fieldstate_path(instance) {
+            Ok(path) => path,
+            Err(err) => {
+                log::error!("Failed to resolve fieldstate for widget '{:?}': {:#?}", self.path, err);
+                return HashMap::new();
+            }
+        };
+
+        let raw = fs::read_to_string(fieldstate_path).unwrap_or(String::from("{}"));
 
         return match serde_json::from_str(&raw) {
             Ok(map) => map,
             Err(err) => {
-                log::error!("Failed to parse fields.json for widget '{:?}': {:#?}", self.path, err);
+                log::error!("Failed to parse fieldstate for widget '{:?}': {:#?}", self.path, err);
                 return HashMap::new();
             }
         };
@@ -145,6 +215,15 @@ impl WidgetMetadata {
     }
 }
 
+pub fn is_valid_widget_name(name: &str) -> bool {
+    return !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+}
+
+/// Instance names share the widget folder name rules, `default` is reserved for the main fieldstate.
+pub fn is_valid_instance_name(instance: &str) -> bool {
+    return instance != "default" && is_valid_widget_name(instance);
+}
+
 const WIDGETS_LAZY_LOCK_KEY: &str = "Widgets::WIDGETS";
 static WIDGETS: LazyLock<RwLock<HashMap<String, WidgetMetadata>>> = LazyLock::new(|| RwLock::new(HashMap::new()));
 
@@ -174,6 +253,111 @@ fn get_warnings_for_widget(widget_path: &Path) -> Vec<String> {
     return warnings;
 }
 
+fn get_manifest_for_widget(widget_path: &Path, warnings: &mut Vec<String>) -> Option<WidgetManifestYAML> {
+    let manifest = match manifest::load_manifest(widget_path) {
+        Ok(manifest) => manifest?,
+        Err(err) => {
+            warnings.push(format!("INVALID_MANIFEST: {}", err));
+            return None;
+        }
+    };
+
+    match manifest.is_compatible() {
+        Ok(Some(false)) => {
+            let version_req = manifest.unichat.clone().unwrap_or_default();
+            warnings.push(format!("INCOMPATIBLE_VERSION: Requires {} version '{}', current version is '{}'", UNICHAT_DISPLAY_NAME, version_req, UNICHAT_VERSION));
+        },
+        Err(err) => warnings.push(format!("INVALID_MANIFEST: {}", err)),
+        _ => {}
+    }
+
+    return Some(manifest);
+}
+
+fn get_fields_warnings(widget_path: &Path) -> Vec<String> {
+    let Ok(raw) = fs::read_to_string(widget_path.join("fields.json")) else {
+        return Vec::new();
+    };
+
+    return fields::validate(&raw).into_iter().map(|err| format!("FIELDS_ERROR: {}", err)).collect();
+}
+
+fn get_fork_for_widget(widget_path: &Path, widgets: &HashMap<String, WidgetMetadata>, warnings: &mut Vec<String>) -> Option<WidgetForkStatus> {
+    let record = fork::load_record(widget_path)?;
+    let source_path = widgets.get(&record.source).map(|w| w.path.as_path());
+    let status = fork::get_status(&record, source_path);
+
+    if status.source_missing {
+        warnings.push(format!("FORK_SOURCE_MISSING: Source widget '{}' is not available", status.source));
+    } else if status.source_changed {
+        warnings.push(format!("FORK_OUTDATED: Source widget '{}' has changed since it was forked", status.source));
+    }
+
+    return Some(status);
+}
+
+fn migrate_fieldstates(metadata: &WidgetMetadata) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    let fields = metadata.fields();
+    if !fields.values().any(|f| f.get("version").is_some() || f.get("renamedFrom").is_some()) {
+        return warnings;
+    }
+
+    let instances = metadata.instances();
+    let targets = std::iter::once(None).chain(instances.iter().map(|i| Some(i.as_str())));
+    for instance in targets {
+        let Ok(fieldstate_path) = metadata.fieldstate_path(instance) else {
+            continue;
+        };
+
+        match migrations::migrate_file(&fields, &fieldstate_path) {
+            Ok(changes) => {
+                for change in changes {
+                    log::info!("Migrated fieldstate '{:?}' of widget '{}': {}", fieldstate_path, metadata.rest_path, change);
+                }
+            }
+            Err(err) => {
+                let file_name = fieldstate_path.file_name().unwrap_or_default().to_string_lossy().to_string();
+                warnings.push(format!("FIELDSTATE_ERROR: {}, {}", file_name, err));
+            }
+        }
+    }
+
+    return warnings;
+}
+
+fn get_template_warnings(metadata: &WidgetMetadata) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    let mut variables: HashMap<String, serde_json::Value> = HashMap::new();
+    for (key, field) in metadata.fields() {
+        let value = field.get("value").cloned().unwrap_or(serde_json::Value::Null);
+        variables.insert(key, fields::serialize_value(&field, &value));
+    }
+    variables.insert(String::from(template::BASE_URL_KEY), serde_json::Value::String(metadata.rest_path.clone()));
+
+    let sources = [
+        (metadata.widget_css_path(), TemplateContext::Css),
+        (metadata.widget_html_path(), TemplateContext::Html),
+        (metadata.widget_js_path(), TemplateContext::Js)
+    ];
+
+    for (path, context) in sources {
+        let Ok(source) = fs::read_to_string(&path) else {
+            continue;
+        };
+
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let (_, template_warnings) = template::render(&source, context, &variables);
+        for warning in template_warnings {
+            warnings.push(format!("TEMPLATE_ERROR: {}, {}", file_name, warning));
+        }
+    }
+
+    return warnings;
+}
+
 fn load_widgets_from_disk(widgets_path: &Path, source_type: WidgetSource, cb: impl Fn(&WidgetMetadata)) -> Result<(), Error> {
     if !widgets_path.exists() || !widgets_path.is_dir() {
         return Ok(());
@@ -192,7 +376,7 @@ fn load_widgets_from_disk(widgets_path: &Path, source_type: WidgetSource, cb: im
             let widget_name = widget_path.file_name().ok_or(anyhow!("Invalid widget folder name"))?.to_string_lossy().to_string();
             if widget_name.starts_with(".") {
                 continue;
-            } else if widget_name.chars().any(|c| !c.is_ascii_alphanumeric() && c != '_' && c != '-') {
+            } else if !is_valid_widget_name(&widget_name) {
                 log::warn!("Skipping widget with invalid name '{}' from {:?}", widget_name, widgets_path);
                 continue;
             }
@@ -206,14 +390,31 @@ fn load_widgets_from_disk(widgets_path: &Path, source_type: WidgetSource, cb: im
                 rest_path = widget_name
             }
 
-            let warnings = get_warnings_for_widget(&widget_path);
+            let mut warnings = get_warnings_for_widget(&widget_path);
+            warnings.extend(get_fields_warnings(&widget_path));
+            let manifest = get_manifest_for_widget(&widget_path, &mut warnings);
+            let preview_url = manifest.as_ref().and_then(|m| m.preview_asset()).map(|preview| {
+                return format!("http://localhost:{}/widget/{}/assets/{}", BASE_REST_PORT, rest_path, preview);
+            });
 
-            let metadata = WidgetMetadata {
+            let mut fork = None;
+            if source_type == WidgetSource::User {
+                fork = get_fork_for_widget(&widget_path, &widgets, &mut warnings);
+            }
+
+            let mut metadata = WidgetMetadata {
                 path: widget_path,
                 rest_path: rest_path.clone(),
                 widget_source: source_type.clone(),
+                manifest: manifest,
+                preview_url: preview_url,
+                fork: fork,
                 warnings: warnings,
             };
+            if matches!(source_type, WidgetSource::User | WidgetSource::UserPlugin(_)) {
+                metadata.warnings.extend(migrate_fieldstates(&metadata));
+            }
+            metadata.warnings.extend(get_template_warnings(&metadata));
             cb(&metadata);
             widgets.insert(rest_path.clone(), metadata);
         }
@@ -238,6 +439,11 @@ pub fn get_widget_from_rest_path(rest_path: &str) -> Result<WidgetMetadata, Erro
     return Err(anyhow!("Widget not found"));
 }
 
+/// User widgets are loaded after system and plugin widgets and would replace them on the same rest path.
+pub fn is_reserved_widget_name(name: &str) -> bool {
+    return get_widget_from_rest_path(name).is_ok_and(|w| w.widget_source != WidgetSource::User);
+}
+
 pub fn reload_user_widgets() -> Result<(), Error> {
     let user_widgets_path = properties::get_app_path(AppPaths::UniChatUserWidgets);
     load_widgets_from_disk(&user_widgets_path, WidgetSource::User, |_| {})?;
@@ -264,6 +470,13 @@ pub fn add_plugin_widgets(plugin: &Arc<UniChatPlugin>) -> Result<(), Error> {
     return Ok(());
 }
 
+pub fn remove_plugin_widgets(plugin_name: &str) -> Result<(), Error> {
+    let mut widgets = WIDGETS.write().map_err(|_| anyhow!("{} lock poisoned", WIDGETS_LAZY_LOCK_KEY))?;
+    widgets.retain(|_, v| !matches!(&v.widget_source, WidgetSource::SystemPlugin(name) | WidgetSource::UserPlugin(name) if name == plugin_name));
+
+    return Ok(());
+}
+
 /* ============================================================================================== */
 
 pub fn init() -> Result<(), Error> {
@@ -0,0 +1,162 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::anyhow;
+use anyhow::Error;
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::UNICHAT_VERSION;
+use crate::widgets::fields;
+use crate::widgets::migrations;
+use crate::widgets::migrations::FIELD_VERSIONS_KEY;
+
+pub const PRESET_EXTENSION: &str = "ucpreset";
+const PRESET_FORMAT: &str = "unichat-widget-preset";
+const PRESET_FORMAT_VERSION: u32 = 1;
+const MAX_PRESET_SIZE: u64 = 4 * 1024 * 1024;
+
+/// A widget fieldstate saved as a shareable file, it can be applied to any widget with matching fields.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetPreset {
+    pub format: String,
+    pub format_version: u32,
+    pub widget: String,
+    pub unichat_version: String,
+    pub fieldstate: Map<String, Value>
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetPresetImport {
+    pub applied: Vec<String>,
+    pub warnings: Vec<String>
+}
+
+impl WidgetPreset {
+    /// Keeps only the values of known fields (and their versions), dividers carry no value.
+    pub fn from_fieldstate(widget: &str, fields: &IndexMap<String, Value>, fieldstate: &Map<String, Value>) -> Self {
+        let mut preset_fieldstate = Map::new();
+        let mut versions = Map::new();
+        let stored_versions = fieldstate.get(FIELD_VERSIONS_KEY).and_then(|v| v.as_object()).cloned().unwrap_or_default();
+
+        for (key, field) in fields {
+            if !field.is_object() || field.get("type").and_then(|t| t.as_str()) == Some("divider") {
+                continue;
+            }
+
+            if let Some(value) = fieldstate.get(key) {
+                preset_fieldstate.insert(key.clone(), value.clone());
+                if let Some(version) = stored_versions.get(key) {
+                    versions.insert(key.clone(), version.clone());
+                }
+            }
+        }
+
+        if !versions.is_empty() {
+            preset_fieldstate.insert(String::from(FIELD_VERSIONS_KEY), Value::Object(versions));
+        }
+
+        return Self {
+            format: String::from(PRESET_FORMAT),
+            format_version: PRESET_FORMAT_VERSION,
+            widget: String::from(widget),
+            unichat_version: String::from(UNICHAT_VERSION),
+            fieldstate: preset_fieldstate
+        };
+    }
+
+    pub fn read(preset_path: &Path) -> Result<Self, Error> {
+        if fs::metadata(preset_path)?.len() > MAX_PRESET_SIZE {
+            return Err(anyhow!("Preset is larger than {} MiB", MAX_PRESET_SIZE / 1024 / 1024));
+        }
+
+        let preset: Self = serde_json::from_slice(&fs::read(preset_path)?)?;
+        if preset.format != PRESET_FORMAT {
+            return Err(anyhow!("File is not a widget preset"));
+        } else if preset.format_version > PRESET_FORMAT_VERSION {
+            return Err(anyhow!("Preset format version {} is not supported", preset.format_version));
+        }
+
+        return Ok(preset);
+    }
+
+    pub fn write(&self, preset_path: &Path) -> Result<(), Error> {
+        fs::write(preset_path, serde_json::to_string_pretty(self)?)?;
+        return Ok(());
+    }
+
+    /// Applies the preset values over `fieldstate`, values of unknown fields or with an invalid type are skipped.
+    pub fn apply(&self, fields: &IndexMap<String, Value>, fieldstate: &mut Map<String, Value>) -> WidgetPresetImport {
+        let mut result = WidgetPresetImport::default();
+
+        // Presets exported from older widget versions go through the same migrations as a stored fieldstate.
+        let mut preset_fieldstate = self.fieldstate.clone();
+        result.warnings.extend(migrations::migrate(fields, &mut preset_fieldstate));
+
+        let preset_versions = preset_fieldstate.remove(FIELD_VERSIONS_KEY).and_then(|v| v.as_object().cloned()).unwrap_or_default();
+        let mut versions = fieldstate.get(FIELD_VERSIONS_KEY).and_then(|v| v.as_object()).cloned().unwrap_or_default();
+
+        for (key, value) in preset_fieldstate {
+            let Some(field) = fields.get(&key).filter(|f| f.is_object()) else {
+                result.warnings.push(format!("Skipped unknown field '{}'", key));
+                continue;
+            };
+
+            if !fields::is_valid_value(field, &value) {
+                result.warnings.push(format!("Skipped field '{}' with an invalid value", key));
+                continue;
+            }
+
+            match preset_versions.get(&key) {
+                Some(version) => versions.insert(key.clone(), version.clone()),
+                None => versions.remove(&key)
+            };
+
+            fieldstate.insert(key.clone(), value);
+            result.applied.push(key);
+        }
+
+        if !versions.is_empty() {
+            fieldstate.insert(String::from(FIELD_VERSIONS_KEY), Value::Object(versions));
+        }
+
+        return result;
+    }
+}
+
+/// Resets the given fields (or all of them) to the `value` default by removing their stored value.
+pub fn reset(fieldstate: &mut Map<String, Value>, keys: Option<&[String]>) -> Vec<String> {
+    let Some(keys) = keys else {
+        let removed: Vec<String> = fieldstate.keys().filter(|k| *k != FIELD_VERSIONS_KEY).cloned().collect();
+        fieldstate.clear();
+        return removed;
+    };
+
+    let mut removed = Vec::new();
+    for key in keys {
+        if fieldstate.remove(key).is_some() {
+            removed.push(key.clone());
+        }
+
+        if let Some(versions) = fieldstate.get_mut(FIELD_VERSIONS_KEY).and_then(|v| v.as_object_mut()) {
+            versions.remove(key);
+        }
+    }
+
+    return removed;
+}
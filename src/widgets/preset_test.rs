@@ -0,0 +1,87 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::fs;
+
+use indexmap::IndexMap;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::utils::test_utils::temp_dir;
+use crate::widgets::preset;
+use crate::widgets::preset::WidgetPreset;
+
+fn to_map<T: serde::de::DeserializeOwned>(value: Value) -> T {
+    return serde_json::from_value(value).unwrap();
+}
+
+fn fields() -> IndexMap<String, Value> {
+    return to_map(json!({
+        "title": { "type": "text", "label": "Title", "value": "Hello" },
+        "size": { "type": "number", "label": "Size", "value": 10, "version": 2 },
+        "section": { "type": "divider", "label": "Section" }
+    }));
+}
+
+#[test]
+fn test_export_and_import_preset() {
+    let dir = temp_dir("preset");
+
+    let fieldstate: Map<String, Value> = to_map(json!({ "title": "Preset", "size": 20, "removed": true, "$fieldVersions": { "size": 2 } }));
+    let preset = WidgetPreset::from_fieldstate("my-widget", &fields(), &fieldstate);
+    assert_eq!(Value::Object(preset.fieldstate.clone()), json!({ "title": "Preset", "size": 20, "$fieldVersions": { "size": 2 } }));
+
+    let preset_path = dir.join("my-widget.ucpreset");
+    preset.write(&preset_path).unwrap();
+    let preset = WidgetPreset::read(&preset_path).unwrap();
+
+    let mut target: Map<String, Value> = to_map(json!({ "title": "Old" }));
+    let result = preset.apply(&fields(), &mut target);
+    assert_eq!(result.applied, vec!["title", "size"]);
+    assert!(result.warnings.is_empty());
+    assert_eq!(Value::Object(target), json!({ "title": "Preset", "size": 20, "$fieldVersions": { "size": 2 } }));
+
+    fs::write(&preset_path, r#"{ "format": "unichat-widget" }"#).unwrap();
+    assert!(WidgetPreset::read(&preset_path).is_err());
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_import_skips_unknown_and_invalid_fields() {
+    let preset = WidgetPreset {
+        format: String::from("unichat-widget-preset"),
+        format_version: 1,
+        widget: String::from("other-widget"),
+        unichat_version: String::from("1.5.0"),
+        fieldstate: to_map(json!({ "title": 42, "size": "12", "unknown": "value" }))
+    };
+
+    let mut target = Map::new();
+    let result = preset.apply(&fields(), &mut target);
+    assert_eq!(result.applied, vec!["size"]);
+    assert_eq!(result.warnings, vec![
+        String::from("Converted field 'size' value from version 1 to 2"),
+        String::from("Skipped field 'title' with an invalid value"),
+        String::from("Skipped unknown field 'unknown'")
+    ]);
+    assert_eq!(Value::Object(target), json!({ "size": 12, "$fieldVersions": { "size": 2 } }));
+}
+
+#[test]
+fn test_reset_fields() {
+    let mut fieldstate: Map<String, Value> = to_map(json!({ "title": "Custom", "size": 20, "$fieldVersions": { "size": 2 } }));
+    assert_eq!(preset::reset(&mut fieldstate, Some(&[String::from("size"), String::from("missing")])), vec!["size"]);
+    assert_eq!(Value::Object(fieldstate.clone()), json!({ "title": "Custom", "$fieldVersions": {} }));
+
+    assert_eq!(preset::reset(&mut fieldstate, None), vec!["title"]);
+    assert!(fieldstate.is_empty());
+}
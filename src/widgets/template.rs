@@ -0,0 +1,722 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use serde_json::Value;
+
+/// Built-in variable holding the widget base URL, available to every widget template.
+pub const BASE_URL_KEY: &str = "WIDGET_BASE_URL";
+
+/// Where a placeholder is rendered, used to choose how its value is escaped.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TemplateContext {
+    Html,
+    Css,
+    Js
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateWarning {
+    pub line: usize,
+    pub message: String
+}
+
+impl Display for TemplateWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "line {}: {}", self.line, self.message);
+    }
+}
+
+/* ================================================================================================================== */
+
+#[derive(Clone, Debug, PartialEq)]
+enum Filter {
+    Raw,
+    Json,
+    Url,
+    Upper,
+    Lower,
+    Trim,
+    Default(Value)
+}
+
+#[derive(Clone, Debug)]
+struct Expression {
+    path: Vec<String>,
+    filters: Vec<Filter>
+}
+
+#[derive(Clone, Debug)]
+enum Node {
+    Text(String),
+    Variable { expression: Expression, context: TemplateContext, legacy: bool, raw: String, line: usize },
+    Conditional { expression: Expression, negate: bool, then_nodes: Vec<Node>, else_nodes: Vec<Node> },
+    Each { expression: Expression, body: Vec<Node>, else_nodes: Vec<Node>, line: usize }
+}
+
+enum Token {
+    Text(String),
+    Tag { inner: String, raw: String, line: usize },
+    Legacy { name: String, raw: String, line: usize }
+}
+
+enum OpenBlock {
+    Conditional { keyword: &'static str, expression: Expression, negate: bool, then_nodes: Vec<Node>, else_nodes: Option<Vec<Node>>, line: usize },
+    Each { expression: Expression, body: Vec<Node>, else_nodes: Option<Vec<Node>>, line: usize }
+}
+
+impl OpenBlock {
+    fn keyword(&self) -> &'static str {
+        return match self {
+            OpenBlock::Conditional { keyword, .. } => keyword,
+            OpenBlock::Each { .. } => "each"
+        };
+    }
+
+    fn line(&self) -> usize {
+        return match self {
+            OpenBlock::Conditional { line, .. } => *line,
+            OpenBlock::Each { line, .. } => *line
+        };
+    }
+
+    fn current_nodes(&mut self) -> &mut Vec<Node> {
+        return match self {
+            OpenBlock::Conditional { then_nodes, else_nodes, .. } => else_nodes.as_mut().unwrap_or(then_nodes),
+            OpenBlock::Each { body, else_nodes, .. } => else_nodes.as_mut().unwrap_or(body)
+        };
+    }
+
+    fn into_node(self) -> Node {
+        return match self {
+            OpenBlock::Conditional { expression, negate, then_nodes, else_nodes, .. } => {
+                Node::Conditional { expression: expression, negate: negate, then_nodes: then_nodes, else_nodes: else_nodes.unwrap_or_default() }
+            },
+            OpenBlock::Each { expression, body, else_nodes, line } => {
+                Node::Each { expression: expression, body: body, else_nodes: else_nodes.unwrap_or_default(), line: line }
+            }
+        };
+    }
+}
+
+/* ================================================================================================================== */
+
+fn is_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+
+    if !first.is_ascii_alphabetic() && first != '_' {
+        return false;
+    }
+
+    return chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+}
+
+fn parse_path(raw: &str) -> Option<Vec<String>> {
+    let mut path: Vec<String> = Vec::new();
+
+    for (idx, segment) in raw.split('.').enumerate() {
+        let is_special = idx == 0 && segment.starts_with('@') && is_identifier(&segment[1..]);
+        let is_index = idx > 0 && !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit());
+        if !is_special && !is_index && !is_identifier(segment) {
+            return None;
+        }
+
+        path.push(segment.to_string());
+    }
+
+    return Some(path);
+}
+
+fn split_filters(raw: &str) -> Vec<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+
+    for c in raw.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {},
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '|' => {
+                parts.push(current.trim().to_string());
+                current = String::new();
+                continue;
+            },
+            None => {}
+        }
+
+        current.push(c);
+    }
+
+    parts.push(current.trim().to_string());
+    return parts;
+}
+
+fn parse_literal(raw: &str) -> Result<Value, String> {
+    let raw = raw.trim();
+    if raw.len() >= 2 && raw.starts_with('\'') && raw.ends_with('\'') {
+        return Ok(Value::String(raw[1..raw.len() - 1].to_string()));
+    }
+
+    return serde_json::from_str(raw).map_err(|_| format!("Invalid literal '{}', strings must be quoted", raw));
+}
+
+fn parse_filter(raw: &str) -> Result<Filter, String> {
+    let (name, argument) = match raw.split_once(':') {
+        Some((name, argument)) => (name.trim(), Some(argument)),
+        None => (raw.trim(), None)
+    };
+
+    let filter = match name {
+        "raw" => Filter::Raw,
+        "json" => Filter::Json,
+        "url" => Filter::Url,
+        "upper" => Filter::Upper,
+        "lower" => Filter::Lower,
+        "trim" => Filter::Trim,
+        "default" => {
+            let argument = argument.ok_or(String::from("Filter 'default' requires a value, e.g. 'default:\"text\"'"))?;
+            return Ok(Filter::Default(parse_literal(argument)?));
+        },
+        _ => return Err(format!("Unknown filter '{}'", name))
+    };
+
+    if argument.is_some() {
+        return Err(format!("Filter '{}' does not accept a value", name));
+    }
+
+    return Ok(filter);
+}
+
+/// Parses `path | filter | filter:arg`. Returns `None` when the path itself is not valid, in which case
+/// the surrounding braces are not treated as a placeholder at all.
+fn parse_expression(raw: &str) -> Option<(Expression, Vec<String>)> {
+    let parts = split_filters(raw);
+    let path = parse_path(&parts[0])?;
+
+    let mut filters: Vec<Filter> = Vec::new();
+    let mut errors: Vec<String> = Vec::new();
+    for part in parts.iter().skip(1) {
+        match parse_filter(part) {
+            Ok(filter) => filters.push(filter),
+            Err(err) => errors.push(err)
+        }
+    }
+
+    return Some((Expression { path: path, filters: filters }, errors));
+}
+
+fn is_block_tag(inner: &str) -> bool {
+    return inner.starts_with('#') || inner.starts_with('/') || inner == "else";
+}
+
+fn tokenize(source: &str) -> Vec<Token> {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut text = String::new();
+    let mut line = 1;
+    let mut idx = 0;
+
+    while idx < source.len() {
+        let rest = &source[idx..];
+
+        if let Some(after) = rest.strip_prefix("{{") {
+            if let Some(end) = after.find("}}") {
+                let inner = &after[..end];
+                let trimmed = inner.trim();
+                let is_tag = !inner.contains('\n') && (is_block_tag(trimmed) || parse_expression(trimmed).is_some());
+
+                if is_tag {
+                    if !text.is_empty() {
+                        tokens.push(Token::Text(std::mem::take(&mut text)));
+                    }
+
+                    let raw = &rest[..end + 4];
+                    tokens.push(Token::Tag { inner: trimmed.to_string(), raw: raw.to_string(), line: line });
+                    idx += raw.len();
+                    continue;
+                }
+            }
+        } else if let Some(after) = rest.strip_prefix('{') {
+            if let Some(end) = after.find('}') {
+                let name = &after[..end];
+                if is_identifier(name) {
+                    if !text.is_empty() {
+                        tokens.push(Token::Text(std::mem::take(&mut text)));
+                    }
+
+                    let raw = &rest[..end + 2];
+                    tokens.push(Token::Legacy { name: name.to_string(), raw: raw.to_string(), line: line });
+                    idx += raw.len();
+                    continue;
+                }
+            }
+        }
+
+        let c = rest.chars().next().unwrap();
+        if c == '\n' {
+            line += 1;
+        }
+
+        text.push(c);
+        idx += c.len_utf8();
+    }
+
+    if !text.is_empty() {
+        tokens.push(Token::Text(text));
+    }
+
+    return tokens;
+}
+
+/// Follows `<script>` and `<style>` tags so placeholders inside them are escaped for JS and CSS.
+fn next_html_context(text: &str, base: TemplateContext, current: TemplateContext) -> TemplateContext {
+    if base != TemplateContext::Html {
+        return current;
+    }
+
+    let lower = text.to_ascii_lowercase();
+    let mut context = current;
+    for (idx, _) in lower.match_indices('<') {
+        let rest = &lower[idx..];
+        if rest.starts_with("<script") {
+            context = TemplateContext::Js;
+        } else if rest.starts_with("<style") {
+            context = TemplateContext::Css;
+        } else if rest.starts_with("</script") || rest.starts_with("</style") {
+            context = TemplateContext::Html;
+        }
+    }
+
+    return context;
+}
+
+/* ================================================================================================================== */
+
+pub struct Template {
+    nodes: Vec<Node>,
+    warnings: Vec<TemplateWarning>
+}
+
+impl Template {
+    pub fn parse(source: &str, context: TemplateContext) -> Self {
+        let mut warnings: Vec<TemplateWarning> = Vec::new();
+        let mut root: Vec<Node> = Vec::new();
+        let mut stack: Vec<OpenBlock> = Vec::new();
+        let mut current_context = context;
+
+        for token in tokenize(source) {
+            let node: Node;
+
+            match token {
+                Token::Text(text) => {
+                    current_context = next_html_context(&text, context, current_context);
+                    node = Node::Text(text);
+                },
+                Token::Legacy { name, raw, line } => {
+                    let expression = Expression { path: vec![name], filters: Vec::new() };
+                    node = Node::Variable { expression: expression, context: current_context, legacy: true, raw: raw, line: line };
+                },
+                Token::Tag { inner, raw, line } => {
+                    let (keyword, argument) = inner.split_once(char::is_whitespace).map(|(k, a)| (k, a.trim())).unwrap_or((inner.as_str(), ""));
+
+                    match keyword {
+                        "#if" | "#unless" | "#each" => {
+                            let Some((expression, errors)) = parse_expression(argument) else {
+                                warnings.push(TemplateWarning { line: line, message: format!("Invalid expression '{}' in '{}'", argument, raw) });
+                                continue;
+                            };
+
+                            for err in errors {
+                                warnings.push(TemplateWarning { line: line, message: err });
+                            }
+
+                            if keyword == "#each" {
+                                stack.push(OpenBlock::Each { expression: expression, body: Vec::new(), else_nodes: None, line: line });
+                            } else {
+                                let negate = keyword == "#unless";
+                                let keyword = if negate { "unless" } else { "if" };
+                                stack.push(OpenBlock::Conditional { keyword: keyword, expression: expression, negate: negate, then_nodes: Vec::new(), else_nodes: None, line: line });
+                            }
+
+                            continue;
+                        },
+                        "else" => {
+                            match stack.last_mut() {
+                                Some(OpenBlock::Conditional { else_nodes, .. }) | Some(OpenBlock::Each { else_nodes, .. }) if else_nodes.is_none() => {
+                                    *else_nodes = Some(Vec::new());
+                                },
+                                Some(_) => warnings.push(TemplateWarning { line: line, message: String::from("Duplicated '{{else}}' in the same block") }),
+                                None => warnings.push(TemplateWarning { line: line, message: String::from("'{{else}}' found outside of a block") })
+                            }
+
+                            continue;
+                        },
+                        "/if" | "/unless" | "/each" => {
+                            let expected = &keyword[1..];
+                            match stack.pop() {
+                                Some(block) if block.keyword() == expected => {
+                                    node = block.into_node();
+                                },
+                                Some(block) => {
+                                    warnings.push(TemplateWarning { line: line, message: format!("Found '{}' but '{{{{#{}}}}}' opened at line {} is still open", raw, block.keyword(), block.line()) });
+                                    stack.push(block);
+                                    continue;
+                                },
+                                None => {
+                                    warnings.push(TemplateWarning { line: line, message: format!("Found '{}' without a matching opening block", raw) });
+                                    continue;
+                                }
+                            }
+                        },
+                        _ if is_block_tag(keyword) => {
+                            warnings.push(TemplateWarning { line: line, message: format!("Unknown block '{}'", raw) });
+                            continue;
+                        },
+                        _ => {
+                            let Some((expression, errors)) = parse_expression(&inner) else {
+                                continue;
+                            };
+
+                            for err in errors {
+                                warnings.push(TemplateWarning { line: line, message: err });
+                            }
+
+                            node = Node::Variable { expression: expression, context: current_context, legacy: false, raw: raw, line: line };
+                        }
+                    }
+                }
+            }
+
+            match stack.last_mut() {
+                Some(block) => block.current_nodes().push(node),
+                None => root.push(node)
+            }
+        }
+
+        while let Some(block) = stack.pop() {
+            warnings.push(TemplateWarning { line: block.line(), message: format!("Block '{{{{#{}}}}}' is never closed", block.keyword()) });
+            let node = block.into_node();
+            match stack.last_mut() {
+                Some(parent) => parent.current_nodes().push(node),
+                None => root.push(node)
+            }
+        }
+
+        return Self { nodes: root, warnings: warnings };
+    }
+
+    pub fn render(&self, variables: &HashMap<String, Value>) -> (String, Vec<TemplateWarning>) {
+        let mut renderer = Renderer { variables: variables, scopes: Vec::new(), output: String::new(), warnings: Vec::new() };
+        renderer.render_nodes(&self.nodes);
+
+        return (renderer.output, renderer.warnings);
+    }
+}
+
+/// Parses and renders `source` in one go, returning parse and render warnings together.
+pub fn render(source: &str, context: TemplateContext, variables: &HashMap<String, Value>) -> (String, Vec<TemplateWarning>) {
+    let template = Template::parse(source, context);
+    let (output, render_warnings) = template.render(variables);
+
+    let mut warnings = template.warnings;
+    warnings.extend(render_warnings);
+
+    return (output, warnings);
+}
+
+/* ================================================================================================================== */
+
+struct Scope {
+    this: Value,
+    key: Value,
+    index: usize,
+    length: usize
+}
+
+struct Renderer<'a> {
+    variables: &'a HashMap<String, Value>,
+    scopes: Vec<Scope>,
+    output: String,
+    warnings: Vec<TemplateWarning>
+}
+
+impl Renderer<'_> {
+    fn lookup(&self, path: &[String]) -> Option<Value> {
+        let (head, tail) = path.split_first()?;
+        let scope = self.scopes.last();
+
+        let mut value = match (head.as_str(), scope) {
+            ("this", Some(scope)) => scope.this.clone(),
+            ("@index", Some(scope)) => Value::from(scope.index),
+            ("@key", Some(scope)) => scope.key.clone(),
+            ("@first", Some(scope)) => Value::Bool(scope.index == 0),
+            ("@last", Some(scope)) => Value::Bool(scope.index + 1 == scope.length),
+            (name, Some(scope)) if scope.this.get(name).is_some() => scope.this[name].clone(),
+            (name, _) => self.variables.get(name)?.clone()
+        };
+
+        for segment in tail {
+            value = match &value {
+                Value::Object(map) => map.get(segment)?.clone(),
+                Value::Array(items) => items.get(segment.parse::<usize>().ok()?)?.clone(),
+                _ => return None
+            };
+        }
+
+        return Some(value);
+    }
+
+    fn render_nodes(&mut self, nodes: &[Node]) {
+        for node in nodes {
+            match node {
+                Node::Text(text) => self.output.push_str(text),
+                Node::Variable { expression, context, legacy, raw, line } => {
+                    let value = self.lookup(&expression.path);
+                    if value.is_none() && *legacy {
+                        // Single brace placeholders are also used by widgets as runtime templates, e.g. `{author_id}`
+                        self.output.push_str(raw);
+                        continue;
+                    }
+
+                    let has_default = expression.filters.iter().any(|f| matches!(f, Filter::Default(_)));
+                    if value.is_none() && !has_default {
+                        self.warnings.push(TemplateWarning { line: *line, message: format!("Unknown field '{}'", expression.path.join(".")) });
+                    }
+
+                    let rendered = apply_filters(value, &expression.filters, *context);
+                    self.output.push_str(&rendered);
+                },
+                Node::Conditional { expression, negate, then_nodes, else_nodes } => {
+                    let value = apply_value_filters(self.lookup(&expression.path), &expression.filters);
+                    if is_truthy(&value) != *negate {
+                        self.render_nodes(then_nodes);
+                    } else {
+                        self.render_nodes(else_nodes);
+                    }
+                },
+                Node::Each { expression, body, else_nodes, line } => {
+                    let value = apply_value_filters(self.lookup(&expression.path), &expression.filters);
+                    let items: Vec<(Value, Value)> = match value {
+                        Some(Value::Array(items)) => items.into_iter().enumerate().map(|(i, v)| (Value::from(i), v)).collect(),
+                        Some(Value::Object(map)) => map.into_iter().map(|(k, v)| (Value::String(k), v)).collect(),
+                        Some(Value::Null) | None => Vec::new(),
+                        Some(_) => {
+                            self.warnings.push(TemplateWarning { line: *line, message: format!("Cannot iterate over '{}', it is not a list", expression.path.join(".")) });
+                            Vec::new()
+                        }
+                    };
+
+                    if items.is_empty() {
+                        self.render_nodes(else_nodes);
+                        continue;
+                    }
+
+                    let length = items.len();
+                    for (index, (key, item)) in items.into_iter().enumerate() {
+                        self.scopes.push(Scope { this: item, key: key, index: index, length: length });
+                        self.render_nodes(body);
+                        self.scopes.pop();
+                    }
+                }
+            }
+        }
+    }
+}
+
+/* ================================================================================================================== */
+
+fn is_truthy(value: &Option<Value>) -> bool {
+    return match value {
+        None | Some(Value::Null) => false,
+        Some(Value::Bool(b)) => *b,
+        Some(Value::Number(n)) => n.as_f64().is_some_and(|n| n != 0.0),
+        Some(Value::String(s)) => !s.is_empty(),
+        Some(Value::Array(items)) => !items.is_empty(),
+        Some(Value::Object(map)) => !map.is_empty()
+    };
+}
+
+pub fn stringify(value: &Value) -> String {
+    return match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Bool(_) | Value::Number(_) => value.to_string(),
+        Value::Array(_) | Value::Object(_) => serde_json::to_string(value).unwrap_or_default()
+    };
+}
+
+/// Applies the filters that change the value itself, used by `#if` and `#each`.
+fn apply_value_filters(value: Option<Value>, filters: &[Filter]) -> Option<Value> {
+    let mut value = value;
+
+    for filter in filters {
+        if let Filter::Default(default_value) = filter {
+            if !is_truthy(&value) && !matches!(value, Some(Value::Bool(false)) | Some(Value::Number(_))) {
+                value = Some(default_value.clone());
+            }
+        }
+    }
+
+    return value;
+}
+
+fn apply_filters(value: Option<Value>, filters: &[Filter], context: TemplateContext) -> String {
+    let mut value = value;
+    let mut text: Option<String> = None;
+    let mut raw = false;
+    let mut json = false;
+
+    for filter in filters {
+        match filter {
+            Filter::Default(_) => value = apply_value_filters(value, std::slice::from_ref(filter)),
+            Filter::Raw => raw = true,
+            Filter::Json => {
+                text = Some(serde_json::to_string(value.as_ref().unwrap_or(&Value::Null)).unwrap_or_default());
+                json = true;
+            },
+            Filter::Url => text = Some(encode_uri_component(&text.unwrap_or_else(|| stringify_option(&value)))),
+            Filter::Upper => text = Some(text.unwrap_or_else(|| stringify_option(&value)).to_uppercase()),
+            Filter::Lower => text = Some(text.unwrap_or_else(|| stringify_option(&value)).to_lowercase()),
+            Filter::Trim => text = Some(text.unwrap_or_else(|| stringify_option(&value)).trim().to_string())
+        }
+    }
+
+    let text = text.unwrap_or_else(|| stringify_option(&value));
+    if raw {
+        return text;
+    }
+
+    return match (context, json) {
+        (TemplateContext::Html, _) => escape_html(&text),
+        (TemplateContext::Css, _) => escape_css(&text),
+        (TemplateContext::Js, true) => escape_js_json(&text),
+        (TemplateContext::Js, false) => escape_js(&text)
+    };
+}
+
+fn stringify_option(value: &Option<Value>) -> String {
+    return value.as_ref().map(stringify).unwrap_or_default();
+}
+
+/* ================================================================================================================== */
+
+pub fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c)
+        }
+    }
+
+    return escaped;
+}
+
+/// Escapes a value to be placed inside a JS string literal. Letters, digits and spaces are kept as is,
+/// so numbers and booleans still work when written without quotes.
+pub fn escape_js(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\'' => escaped.push_str("\\'"),
+            '`' => escaped.push_str("\\`"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '<' | '>' | '&' | '$' | '\u{2028}' | '\u{2029}' => escaped.push_str(&format!("\\u{:04X}", c as u32)),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04X}", c as u32)),
+            _ => escaped.push(c)
+        }
+    }
+
+    return escaped;
+}
+
+/// JSON is already a valid JS expression, only sequences that could close the `<script>` tag are escaped.
+fn escape_js_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '<' | '>' | '&' | '\u{2028}' | '\u{2029}' => escaped.push_str(&format!("\\u{:04X}", c as u32)),
+            _ => escaped.push(c)
+        }
+    }
+
+    return escaped;
+}
+
+/// Escapes characters that could end the current declaration, rule or `<style>` tag. Quotes are kept so
+/// values like font family lists keep working.
+pub fn escape_css(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '\\' | '<' | '>' | '{' | '}' | ';' | '\n' | '\r' => escaped.push_str(&format!("\\{:X} ", c as u32)),
+            _ => escaped.push(c)
+        }
+    }
+
+    return escaped;
+}
+
+/// Replaces the `{{KEY}}` placeholders of the page shell in a single pass, so placeholders inside the
+/// inserted values are never expanded. Unknown placeholders are kept as is.
+pub fn fill_placeholders(source: &str, values: &[(&str, &str)]) -> String {
+    let mut output = String::with_capacity(source.len());
+    let mut rest = source;
+
+    while let Some(start) = rest.find("{{") {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            rest = &rest[start..];
+            break;
+        };
+
+        let key = &after[..end];
+        match values.iter().find(|(k, _)| *k == key) {
+            Some((_, value)) => output.push_str(value),
+            None => output.push_str(&rest[start..start + end + 4])
+        }
+
+        rest = &after[end + 2..];
+    }
+
+    output.push_str(rest);
+    return output;
+}
+
+pub fn encode_uri_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+
+    for byte in value.as_bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(*byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte))
+        }
+    }
+
+    return encoded;
+}
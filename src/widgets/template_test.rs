@@ -0,0 +1,141 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::collections::HashMap;
+
+use serde_json::json;
+use serde_json::Value;
+
+use crate::widgets::template::fill_placeholders;
+use crate::widgets::template::render;
+use crate::widgets::template::TemplateContext;
+
+fn variables(value: Value) -> HashMap<String, Value> {
+    return serde_json::from_value(value).unwrap();
+}
+
+#[test]
+fn test_render_double_and_single_brace() {
+    let vars = variables(json!({ "size": 12, "color": "#FFF" }));
+    let (output, warnings) = render("a: {{size}}px; b: {color};", TemplateContext::Css, &vars);
+    assert_eq!(output, "a: 12px; b: #FFF;");
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_render_keeps_unknown_single_brace() {
+    let vars = variables(json!({ "platform": "youtube" }));
+    let (output, warnings) = render("<div data-from=\"{author_id}\">{platform}</div>", TemplateContext::Html, &vars);
+    assert_eq!(output, "<div data-from=\"{author_id}\">youtube</div>");
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_render_ignores_code_braces() {
+    let vars = variables(json!({}));
+    let source = "const a = { b: 1 }; const c = `${d}`; function e() {{ return 1; }}";
+    let (output, warnings) = render(source, TemplateContext::Js, &vars);
+    assert_eq!(output, source);
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_render_escapes_by_context() {
+    let vars = variables(json!({ "text": "</script><b>\"it's\"</b>" }));
+
+    let (output, _) = render("<p>{{text}}</p>", TemplateContext::Html, &vars);
+    assert_eq!(output, "<p>&lt;/script&gt;&lt;b&gt;&quot;it&#39;s&quot;&lt;/b&gt;</p>");
+
+    let (output, _) = render("const t = \"{{text}}\";", TemplateContext::Js, &vars);
+    assert_eq!(output, "const t = \"\\u003C/script\\u003E\\u003Cb\\u003E\\\"it\\'s\\\"\\u003C/b\\u003E\";");
+
+    let (output, _) = render("content: \"{{text}}\";", TemplateContext::Css, &vars);
+    assert!(!output.contains("</script>"));
+}
+
+#[test]
+fn test_render_html_follows_script_and_style_tags() {
+    let vars = variables(json!({ "text": "a\"b" }));
+    let (output, _) = render("<i>{{text}}</i><script>var x = \"{{text}}\";</script><i>{{text}}</i>", TemplateContext::Html, &vars);
+    assert_eq!(output, "<i>a&quot;b</i><script>var x = \"a\\\"b\";</script><i>a&quot;b</i>");
+}
+
+#[test]
+fn test_render_filters() {
+    let vars = variables(json!({ "html": "<b>x</b>", "list": ["a", "b"], "name": "Hello World" }));
+
+    let (output, _) = render("{{html | raw}}", TemplateContext::Html, &vars);
+    assert_eq!(output, "<b>x</b>");
+
+    let (output, _) = render("const l = {{list|json}};", TemplateContext::Js, &vars);
+    assert_eq!(output, "const l = [\"a\",\"b\"];");
+
+    let (output, _) = render("{{name|url}} {{name|upper}}", TemplateContext::Html, &vars);
+    assert_eq!(output, "Hello%20World HELLO WORLD");
+
+    let (output, warnings) = render("{{missing|default:\"fallback\"}}", TemplateContext::Html, &vars);
+    assert_eq!(output, "fallback");
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_render_conditionals() {
+    let vars = variables(json!({ "on": true, "off": false, "empty": "" }));
+    let source = "{{#if on}}A{{else}}B{{/if}}{{#if off}}C{{else}}D{{/if}}{{#unless empty}}E{{/unless}}";
+    let (output, warnings) = render(source, TemplateContext::Html, &vars);
+    assert_eq!(output, "ADE");
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_render_each() {
+    let vars = variables(json!({ "items": [{ "name": "a" }, { "name": "b" }], "map": { "x": 1 }, "none": [] }));
+
+    let (output, _) = render("{{#each items}}{{@index}}:{{name}}{{#unless @last}},{{/unless}}{{/each}}", TemplateContext::Html, &vars);
+    assert_eq!(output, "0:a,1:b");
+
+    let (output, _) = render("{{#each map}}{{@key}}={{this}}{{/each}}", TemplateContext::Html, &vars);
+    assert_eq!(output, "x=1");
+
+    let (output, _) = render("{{#each none}}X{{else}}empty{{/each}}", TemplateContext::Html, &vars);
+    assert_eq!(output, "empty");
+}
+
+#[test]
+fn test_render_reports_warnings() {
+    let vars = variables(json!({ "a": 1 }));
+
+    let (_, warnings) = render("{{#if a}}\nx", TemplateContext::Html, &vars);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].line, 1);
+
+    let (_, warnings) = render("x\n{{/each}}", TemplateContext::Html, &vars);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].line, 2);
+
+    let (_, warnings) = render("{{a|nope}} {{b}}", TemplateContext::Html, &vars);
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings[0].message.contains("nope"));
+    assert!(warnings[1].message.contains("'b'"));
+}
+
+#[test]
+fn test_render_does_not_reprocess_values() {
+    let vars = variables(json!({ "message": "Just donated {value}!", "value": 10 }));
+    let (output, _) = render("{{message}}", TemplateContext::Html, &vars);
+    assert_eq!(output, "Just donated {value}!");
+}
+
+#[test]
+fn test_fill_placeholders_single_pass() {
+    let values = [("WIDGET_HTML", "<p>{{WIDGET_SCRIPT}}</p>"), ("WIDGET_SCRIPT", "alert(1)")];
+    let output = fill_placeholders("{{WIDGET_HTML}}<script>{{WIDGET_SCRIPT}}</script>{{OTHER}}{{", &values);
+    assert_eq!(output, "<p>{{WIDGET_SCRIPT}}</p><script>alert(1)</script>{{OTHER}}{{");
+}
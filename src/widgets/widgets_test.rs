@@ -0,0 +1,74 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+use std::fs;
+use std::path::Path;
+
+use crate::utils::test_utils::temp_dir;
+use crate::widgets::is_valid_instance_name;
+use crate::widgets::WidgetMetadata;
+use crate::widgets::WidgetSource;
+
+fn metadata(path: &Path) -> WidgetMetadata {
+    return WidgetMetadata {
+        path: path.to_path_buf(),
+        rest_path: String::from("my-widget"),
+        widget_source: WidgetSource::User,
+        manifest: None,
+        preview_url: None,
+        fork: None,
+        warnings: Vec::new()
+    };
+}
+
+#[test]
+fn test_instance_names() {
+    assert!(is_valid_instance_name("scene-1"));
+    assert!(is_valid_instance_name("Main_Scene"));
+    assert!(!is_valid_instance_name("default"));
+    assert!(!is_valid_instance_name(""));
+    assert!(!is_valid_instance_name("../fieldstate"));
+    assert!(!is_valid_instance_name("scene/1"));
+    assert!(!is_valid_instance_name("scene.json"));
+}
+
+#[test]
+fn test_fieldstate_path() {
+    let widget_path = temp_dir("widget-fieldstate-path");
+    let metadata = metadata(&widget_path);
+
+    assert_eq!(metadata.fieldstate_path(None).unwrap(), widget_path.join("fieldstate.json"));
+    assert_eq!(metadata.fieldstate_path(Some("scene-1")).unwrap(), widget_path.join("instances").join("scene-1.json"));
+    assert!(metadata.fieldstate_path(Some("default")).is_err());
+    assert!(metadata.fieldstate_path(Some("../../other-widget/fieldstate")).is_err());
+    assert!(metadata.fieldstate_path(Some("..")).is_err());
+
+    let _ = fs::remove_dir_all(&widget_path);
+}
+
+#[test]
+fn test_instances() {
+    let widget_path = temp_dir("widget-instances");
+    let metadata = metadata(&widget_path);
+    assert!(metadata.instances().is_empty());
+
+    let instances_path = widget_path.join("instances");
+    fs::create_dir_all(instances_path.join("nested.json")).unwrap();
+    fs::write(instances_path.join("scene-2.json"), "{}").unwrap();
+    fs::write(instances_path.join("scene-1.json"), "{}").unwrap();
+    fs::write(instances_path.join("default.json"), "{}").unwrap();
+    fs::write(instances_path.join("notes.txt"), "").unwrap();
+    fs::write(instances_path.join("scene 3.json"), "{}").unwrap();
+    fs::write(instances_path.join("scene-4.json.bak"), "{}").unwrap();
+
+    assert_eq!(metadata.instances(), vec!["scene-1", "scene-2"]);
+
+    let _ = fs::remove_dir_all(&widget_path);
+}
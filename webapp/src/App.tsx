@@ -116,6 +116,18 @@ export function App(): PReact.ComponentChildren {
 
         /* ====================================================================================== */
 
+        const plugins = await commandService.getPlugins();
+        for (const plugin of plugins.filter((p) => p.pendingPermissions.length > 0)) {
+            notificationService.info({
+                title: `Plugin '${plugin.name}' permissions`,
+                message: `Granted permissions: ${plugin.pendingPermissions.join(", ")}.`
+            });
+
+            await commandService.acknowledgePluginPermissions(plugin.name);
+        }
+
+        /* ====================================================================================== */
+
         const releaseInfo = await commandService.getReleases();
         if (releaseInfo.hasUpdate) {
             setOpenedSettingsModal("check-updates");
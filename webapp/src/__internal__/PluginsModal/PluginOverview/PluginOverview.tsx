@@ -68,6 +68,12 @@ export function PluginOverviewModal({ plugin }: Props): PReact.ComponentChildren
                         <div className="details-label">Description</div>
                         <div className="details-value">{plugin.description}</div>
                     </div>
+                    <div className="plugin-permissions">
+                        <div className="details-label">Permissions</div>
+                        <div className="details-value">
+                            {plugin.permissions.length > 0 ? plugin.permissions.map((p) => <div key={p}>{p}</div>) : "None"}
+                        </div>
+                    </div>
                 </div>
             </div>
             <pre className="plugin-messages">{plugin.messages.join("\n")}</pre>
@@ -12,17 +12,74 @@ import * as PReact from "preact";
 
 import { revealItemInDir } from "@tauri-apps/plugin-opener";
 
+import { PluginSettingsModal } from "unichat/__internal__/PluginsModal/PluginSettings";
 import { Button } from "unichat/components/Button";
+import { LoggerFactory } from "unichat/logging/LoggerFactory";
+import { commandService } from "unichat/services/commandService";
+import { modalService } from "unichat/services/modalService";
+import { notificationService } from "unichat/services/notificationService";
 import { UniChatPluginMetadata } from "unichat/types";
+import { PluginStatus } from "unichat/utils/constants";
 import { Strings } from "unichat/utils/Strings";
 
 interface Props {
     plugin: UniChatPluginMetadata;
+    onChange?: () => void;
 }
 
-export function PluginOverviewModalActions({ plugin: { pluginPath } }: Props): PReact.ComponentChildren {
+const _logger = LoggerFactory.getLogger("PluginOverviewModalActions");
+export function PluginOverviewModalActions({ plugin, onChange }: Props): PReact.ComponentChildren {
+    const { name, pluginPath, status, hasSettings } = plugin;
+    const isDisabled = status === PluginStatus.DISABLED;
+
+    function handleOpenSettings(): void {
+        modalService.openModal({
+            fullscreen: true,
+            title: `${name} Settings`,
+            children: <PluginSettingsModal plugin={plugin} />
+        });
+    }
+
+    async function handleReload(): Promise<void> {
+        try {
+            const plugin = await commandService.reloadPlugin(name);
+            notificationService.success({ title: "Success", message: `Plugin '${name}' reloaded with status ${plugin.status}.` });
+            onChange?.();
+        } catch (error) {
+            _logger.error("An error occurred on reload plugin", error);
+
+            notificationService.error({ title: "Reload Error", message: `An error occurred while reloading plugin '${name}'.` });
+        }
+    }
+
+    async function handleToggleState(): Promise<void> {
+        try {
+            await commandService.togglePluginState(name, isDisabled);
+            notificationService.success({ title: "Success", message: `Plugin '${name}' ${isDisabled ? "enabled" : "disabled"}.` });
+            onChange?.();
+        } catch (error) {
+            _logger.error("An error occurred on toggle plugin state", error);
+
+            notificationService.error({ title: "Plugin Error", message: `An error occurred while changing plugin '${name}' state.` });
+        }
+    }
+
     return (
         <>
+            <Button onClick={handleToggleState}>
+                <i className={isDisabled ? "fas fa-play" : "fas fa-stop"} />
+                &nbsp;{isDisabled ? "Enable" : "Disable"}
+            </Button>
+            {hasSettings && (
+                <Button onClick={handleOpenSettings}>
+                    <i className="fas fa-cog" />
+                    &nbsp;Settings
+                </Button>
+            )}
+            <Button onClick={handleReload} disabled={isDisabled}>
+                <i className="fas fa-sync" />
+                &nbsp;Reload
+            </Button>
             {Strings.isNullOrEmpty(pluginPath) ? (
                 <Button disabled>Built-In Plugin</Button>
             ) : (
@@ -42,7 +42,7 @@ export const PluginOverviewStyledContainer: ComponentType<HTMLAttributes<HTMLDiv
             gridTemplateAreas: `
                 "PN PN PN PN PN PN PN PV PV PV PS PS"
                 "PA PA PA PL PL PH PH PH PH PH PH PH"
-                "PD PD PD PD PD PD PD PD PD PD PD PD"
+                "PD PD PD PD PD PD PD PD PP PP PP PP"
             `,
 
             "> div": {
@@ -92,6 +92,17 @@ export const PluginOverviewStyledContainer: ComponentType<HTMLAttributes<HTMLDiv
                         whiteSpace: "normal",
                         lineClamp: "unset"
                     }
+                },
+
+                "&.plugin-permissions": {
+                    gridArea: "PP",
+
+                    "> .details-value": {
+                        ...tw`font-mono text-xs`,
+                        height: "calc(128px - 14px)",
+                        overflowY: "auto",
+                        lineClamp: "unset"
+                    }
                 }
             }
         }
@@ -0,0 +1,97 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+import * as PReact from "preact";
+import { useEffect, useState } from "preact/hooks";
+
+import { Button } from "unichat/components/Button";
+import { FieldInput } from "unichat/components/forms/FieldInput";
+import { LoggerFactory } from "unichat/logging/LoggerFactory";
+import { commandService } from "unichat/services/commandService";
+import { notificationService } from "unichat/services/notificationService";
+import { UniChatPluginMetadata, WidgetFields } from "unichat/types";
+
+import { PluginSettingsStyledContainer } from "./styled";
+
+interface Props {
+    plugin: UniChatPluginMetadata;
+}
+
+/** Number inputs keep the typed text, settings are validated against the field type on save. */
+function normalizeValues(fields: Record<string, WidgetFields>, values: Record<string, unknown>): Record<string, unknown> {
+    const normalized: Record<string, unknown> = {};
+    for (const [key, value] of Object.entries(values)) {
+        const isNumber = fields[key]?.type === "number";
+        normalized[key] = isNumber && typeof value === "string" && value.trim() !== "" ? Number(value) : value;
+    }
+
+    return normalized;
+}
+
+const _logger = LoggerFactory.getLogger("PluginSettings");
+export function PluginSettingsModal({ plugin }: Props): PReact.ComponentChildren {
+    const [fields, setFields] = useState<Record<string, WidgetFields>>({});
+    const [values, setValues] = useState<Record<string, unknown>>({});
+
+    async function handleFetchSettings(): Promise<void> {
+        try {
+            const fields = await commandService.getPluginSettingFields(plugin.name);
+            const values = await commandService.getPluginSettings(plugin.name);
+
+            setFields(fields);
+            setValues(values);
+        } catch (error) {
+            _logger.error("An error occurred on fetch plugin settings", error);
+
+            notificationService.error({ title: "Settings Error", message: `An error occurred while loading plugin '${plugin.name}' settings.` });
+        }
+    }
+
+    async function handleApply(): Promise<void> {
+        try {
+            const saved = await commandService.setPluginSettings(plugin.name, normalizeValues(fields, values));
+            setValues(saved);
+            notificationService.success({ title: "Success", message: `Plugin '${plugin.name}' settings saved.` });
+        } catch (error) {
+            _logger.error("An error occurred on save plugin settings", error);
+
+            notificationService.error({ title: "Settings Error", message: `An error occurred while saving plugin '${plugin.name}' settings.` });
+        }
+    }
+
+    useEffect(() => {
+        handleFetchSettings();
+    }, [plugin.name]);
+
+    return (
+        <PluginSettingsStyledContainer>
+            <div className="settings--content">
+                {Object.keys(fields).length === 0 ? (
+                    <div className="empty-settings">No settings defined for this plugin.</div>
+                ) : (
+                    Object.entries(fields).map(([key, builder]) => (
+                        <FieldInput
+                            key={key}
+                            fieldKey={key}
+                            builder={builder}
+                            value={values[key]}
+                            onChange={(newValue) => setValues((old) => ({ ...old, [key]: newValue }))}
+                        />
+                    ))
+                )}
+            </div>
+            <div className="settings--actions">
+                <Button variant="primary" onClick={handleApply}>
+                    Apply
+                </Button>
+            </div>
+        </PluginSettingsStyledContainer>
+    );
+}
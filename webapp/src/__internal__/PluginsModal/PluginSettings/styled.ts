@@ -0,0 +1,37 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+import { ComponentType, HTMLAttributes } from "preact";
+
+import { styled } from "goober";
+import tw from "twin.macro";
+
+export const PluginSettingsStyledContainer: ComponentType<HTMLAttributes<HTMLDivElement>> = styled.div({
+    ...tw`relative flex flex-col gap-2`,
+    height: "calc(100vh - (47px + 28px))",
+
+    "> .settings--content": {
+        ...tw`flex-1 p-2 flex flex-col gap-4 overflow-y-auto bg-stone-700/25 rounded`,
+
+        "> .form-group": {
+            "> .form-description": {
+                ...tw`text-stone-50/50`
+            }
+        },
+
+        "> .empty-settings": {
+            ...tw`w-full h-full flex justify-center items-center text-stone-50/50`
+        }
+    },
+
+    "> .settings--actions": {
+        ...tw`flex justify-end gap-2`
+    }
+});
@@ -30,14 +30,19 @@ export function PluginsModal(): PReact.ComponentChildren {
     const [plugins, setPlugins] = useState<UniChatPluginMetadata[]>([]);
 
     function openPluginDetails(plugin: UniChatPluginMetadata): void {
-        modalService.openModal({
+        const modalId = modalService.openModal({
             fullscreen: true,
             title: "Plugin Overview",
-            actions: <PluginOverviewModalActions plugin={plugin} />,
+            actions: <PluginOverviewModalActions plugin={plugin} onChange={() => handlePluginChange(modalId)} />,
             children: <PluginOverviewModal plugin={plugin} />
         });
     }
 
+    function handlePluginChange(modalId: string): void {
+        modalService.closeModal(modalId);
+        handleFetchPlugins();
+    }
+
     async function handleFetchPlugins(): Promise<void> {
         try {
             const items = await commandService.getPlugins();
@@ -14,6 +14,7 @@ import { useEffect, useRef, useState } from "preact/hooks";
 import { Button } from "unichat/components/Button";
 import { Option, Select } from "unichat/components/forms/Select";
 import { Switch } from "unichat/components/forms/Switch";
+import { TextInput } from "unichat/components/forms/TextInput";
 import { useStorage } from "unichat/hooks/useStorage";
 import { useWidgets } from "unichat/hooks/useWidgets";
 import { commandService } from "unichat/services/commandService";
@@ -39,6 +40,7 @@ export function GeneralSettingsTab({ onClose }: Props): PReact.ComponentChildren
     const selectRef = useRef<HTMLInputElement>(null);
     const openToLanRef = useRef<HTMLInputElement>(null);
     const currencyRef = useRef<HTMLInputElement>(null);
+    const catalogUrlRef = useRef<HTMLInputElement>(null);
 
     const [requiresRestart, setRequiresRestart] = useStorage(StorageKeys.REQUIRES_RESTART);
 
@@ -61,6 +63,11 @@ export function GeneralSettingsTab({ onClose }: Props): PReact.ComponentChildren
             settingsCopy[UniChatSettingsKeys.CURRENCY_TARGET] = value;
         }
 
+        if (catalogUrlRef.current != null) {
+            const value = catalogUrlRef.current.value.trim();
+            settingsCopy[UniChatSettingsKeys.CATALOG_URL] = value;
+        }
+
         await settingsService.setItems(settingsCopy);
         setInitialSettings(settingsCopy);
         setDirty(false);
@@ -97,6 +104,10 @@ export function GeneralSettingsTab({ onClose }: Props): PReact.ComponentChildren
             currencyRef.current.addEventListener("change", changeDirty);
         }
 
+        if (catalogUrlRef.current) {
+            catalogUrlRef.current.addEventListener("change", changeDirty);
+        }
+
         return () => {
             if (selectRef.current) {
                 selectRef.current.removeEventListener("change", changeDirty);
@@ -109,6 +120,10 @@ export function GeneralSettingsTab({ onClose }: Props): PReact.ComponentChildren
             if (currencyRef.current) {
                 currencyRef.current.removeEventListener("change", changeDirty);
             }
+
+            if (catalogUrlRef.current) {
+                catalogUrlRef.current.removeEventListener("change", changeDirty);
+            }
         };
     }, [initialSettings]);
 
@@ -117,7 +132,8 @@ export function GeneralSettingsTab({ onClose }: Props): PReact.ComponentChildren
             const settings = await settingsService.getItems([
                 UniChatSettingsKeys.DEFAULT_PREVIEW_WIDGET,
                 UniChatSettingsKeys.OPEN_TO_LAN,
-                UniChatSettingsKeys.CURRENCY_TARGET
+                UniChatSettingsKeys.CURRENCY_TARGET,
+                UniChatSettingsKeys.CATALOG_URL
             ]);
 
             setInitialSettings(settings);
@@ -159,6 +175,13 @@ export function GeneralSettingsTab({ onClose }: Props): PReact.ComponentChildren
                 data-tour="currency-target"
             />
 
+            <TextInput
+                defaultValue={initialSettings[UniChatSettingsKeys.CATALOG_URL] ?? ""}
+                inputRef={catalogUrlRef}
+                label="Catalog URL"
+                description="URL or local file of the widgets and plugins catalog index, the catalog is disabled while empty"
+            />
+
             <hr />
 
             <div className="openToLan-section">
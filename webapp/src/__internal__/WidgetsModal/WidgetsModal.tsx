@@ -11,6 +11,7 @@
 import * as PReact from "preact";
 import { useState } from "preact/hooks";
 
+import * as dialog from "@tauri-apps/plugin-dialog";
 import { openUrl, revealItemInDir } from "@tauri-apps/plugin-opener";
 
 import { AccordionItem } from "unichat/components/AccordionItem";
@@ -18,11 +19,14 @@ import { Badge } from "unichat/components/Badge";
 import { Button } from "unichat/components/Button";
 import { Tooltip } from "unichat/components/Tooltip";
 import { useWidgets } from "unichat/hooks/useWidgets";
+import { LoggerFactory } from "unichat/logging/LoggerFactory";
+import { commandService } from "unichat/services/commandService";
 import { Variants } from "unichat/types";
 import { toWidgetOptionGroup } from "unichat/utils/toWidgetOptionGroup";
 
 import { WidgetsStyledTable } from "./styled";
 
+const _logger = LoggerFactory.getLogger("WidgetsModal");
 interface WidgetWarning {
     message: string | PReact.ComponentChildren;
     details: string;
@@ -31,7 +35,7 @@ interface WidgetWarning {
 
 export function WidgetsModal(): PReact.ComponentChildren {
     const [openedWidgetGroup, setOpenedWidgetGroup] = useState<string | null>(null);
-    const [widgets, _reloadWidgets] = useWidgets(toWidgetOptionGroup, []);
+    const [widgets, reloadWidgets] = useWidgets(toWidgetOptionGroup, []);
 
     function formatWarning(warning: string): WidgetWarning | undefined {
         switch (warning) {
@@ -96,6 +100,118 @@ export function WidgetsModal(): PReact.ComponentChildren {
                     variant: "warning"
                 };
         }
+
+        const [code, details] = splitWarning(warning);
+        switch (code) {
+            case "TEMPLATE_ERROR":
+                return {
+                    message: (
+                        <>
+                            <i className="fas fa-code" /> Template
+                        </>
+                    ),
+                    details: details,
+                    variant: "warning"
+                };
+            case "FIELDS_ERROR":
+                return {
+                    message: (
+                        <>
+                            <i className="fas fa-sliders" /> Fields
+                        </>
+                    ),
+                    details: details,
+                    variant: "danger"
+                };
+            case "FIELDSTATE_ERROR":
+                return {
+                    message: (
+                        <>
+                            <i className="fas fa-sliders" /> Fieldstate
+                        </>
+                    ),
+                    details: details,
+                    variant: "danger"
+                };
+            case "FORK_OUTDATED":
+                return {
+                    message: (
+                        <>
+                            <i className="fas fa-code-fork" /> Outdated
+                        </>
+                    ),
+                    details: details,
+                    variant: "warning"
+                };
+            case "FORK_SOURCE_MISSING":
+                return {
+                    message: (
+                        <>
+                            <i className="fas fa-code-fork" /> Missing
+                        </>
+                    ),
+                    details: details,
+                    variant: "warning"
+                };
+            case "INVALID_MANIFEST":
+                return {
+                    message: (
+                        <>
+                            <i className="fas fa-file-code" /> Manifest
+                        </>
+                    ),
+                    details: details,
+                    variant: "danger"
+                };
+            case "INCOMPATIBLE_VERSION":
+                return {
+                    message: (
+                        <>
+                            <i className="fas fa-triangle-exclamation" /> Incompatible
+                        </>
+                    ),
+                    details: details,
+                    variant: "danger"
+                };
+        }
+    }
+
+    function splitWarning(warning: string): [string, string] {
+        const separatorIndex = warning.indexOf(": ");
+        if (separatorIndex === -1) {
+            return [warning, ""];
+        }
+
+        return [warning.slice(0, separatorIndex), warning.slice(separatorIndex + 2)];
+    }
+
+    async function exportWidget(widget: string): Promise<void> {
+        try {
+            const path = await dialog.save({
+                defaultPath: `${widget.replace("::", "-")}.ucwidget`,
+                filters: [{ name: "UniChat Widget", extensions: ["ucwidget"] }]
+            });
+            if (path == null) {
+                return;
+            }
+
+            const includeFieldstate = await dialog.ask("Include the widget settings (fieldstate and instances)?", {
+                title: "Export Widget",
+                kind: "info"
+            });
+            await commandService.exportWidget(widget, path, includeFieldstate);
+        } catch (error) {
+            _logger.error("An error occurred on export widget", error);
+        }
+    }
+
+    async function forkWidget(widget: string): Promise<void> {
+        try {
+            await commandService.forkWidget(widget, `${widget.replace("::", "-")}-custom`);
+            await reloadWidgets();
+        } catch (error) {
+            _logger.error("An error occurred on fork widget", error);
+        }
     }
 
     function formatWarnings(warnings: string[]): PReact.ComponentChildren {
@@ -151,6 +267,20 @@ export function WidgetsModal(): PReact.ComponentChildren {
                                                     </Tooltip>
                                                 )}
 
+                                                {widgetGroup.label !== "User Widgets" && (
+                                                    <Tooltip content="Fork as User Widget" placement="bottom">
+                                                        <Button variant="default" onClick={() => forkWidget(widget.value)}>
+                                                            <i className="fas fa-code-fork" />
+                                                        </Button>
+                                                    </Tooltip>
+                                                )}
+
+                                                <Tooltip content="Export Widget" placement="bottom">
+                                                    <Button variant="default" onClick={() => exportWidget(widget.value)}>
+                                                        <i className="fas fa-file-export" />
+                                                    </Button>
+                                                </Tooltip>
+
                                                 <Tooltip content="Open in Browser" placement="bottom">
                                                     <Button variant="default" onClick={() => openUrl(widget.label)}>
                                                         <i className="fas fa-external-link-alt" />
@@ -10,11 +10,55 @@
 
 import * as PReact from "preact";
 
+import * as dialog from "@tauri-apps/plugin-dialog";
 import { openUrl, revealItemInDir } from "@tauri-apps/plugin-opener";
 
 import { Button } from "unichat/components/Button";
+import { LoggerFactory } from "unichat/logging/LoggerFactory";
+import { commandService } from "unichat/services/commandService";
 
+const _logger = LoggerFactory.getLogger("WidgetsModalActions");
 export function WidgetsModalActions(): PReact.ComponentChildren {
+    async function onImportClick(): Promise<void> {
+        try {
+            const path = await dialog.open({
+                multiple: false,
+                directory: false,
+                filters: [{ name: "UniChat Widget", extensions: ["ucwidget"] }]
+            });
+            if (path == null || Array.isArray(path)) {
+                return;
+            }
+
+            const preview = await commandService.previewWidgetArchive(path);
+            const details = [
+                `Widget: ${preview.manifest?.name ?? preview.name}${preview.manifest?.version ? ` v${preview.manifest.version}` : ""}`,
+                preview.manifest?.author ? `Author: ${preview.manifest.author}` : null,
+                `Files: ${preview.files.length} (${(preview.size / 1024).toFixed(1)} KiB)`,
+                preview.hasFieldstate || preview.instances.length > 0 ? "Includes widget settings" : null,
+                ...preview.warnings.map((warning) => `Warning: ${warning}`)
+            ].filter((line) => line != null);
+
+            if (!(await dialog.ask(details.join("\n"), { title: "Import Widget", kind: "info" }))) {
+                return;
+            }
+
+            let name = preview.name;
+            let overwrite = false;
+            if (preview.conflict) {
+                overwrite = await dialog.ask(
+                    `Widget '${preview.name}' already exists. Replace it (its settings are kept) or install as '${preview.suggestedName}'?`,
+                    { title: "Import Widget", kind: "warning", okLabel: "Replace", cancelLabel: `Install as '${preview.suggestedName}'` }
+                );
+                name = overwrite ? preview.name : preview.suggestedName;
+            }
+
+            await commandService.importWidgetArchive(path, name, overwrite);
+        } catch (error) {
+            _logger.error("An error occurred on import widget", error);
+        }
+    }
+
     return (
         <>
             <Button
@@ -24,6 +68,10 @@ export function WidgetsModalActions(): PReact.ComponentChildren {
                 <i className="fas fa-book" />
                 Read the Docs
             </Button>
+            <Button variant="secondary" onClick={onImportClick}>
+                <i className="fas fa-file-import" />
+                Import Widget
+            </Button>
             <Button onClick={() => revealItemInDir(UNICHAT_WIDGETS_DIR)}>
                 <i className="fas fa-folder" />
                 Show Widgets Folder
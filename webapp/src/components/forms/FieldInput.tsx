@@ -0,0 +1,220 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+import * as PReact from "preact";
+
+import { UniChatEvent } from "unichat-widgets/unichat";
+
+import { WidgetFields } from "unichat/types";
+import { Strings } from "unichat/utils/Strings";
+
+import { ColorPicker } from "./ColorPicker";
+import { FormGroup } from "./FormGroup";
+import { GalleryFileInput } from "./GalleryFileInput";
+import { NumberInput } from "./NumberInput";
+import { RangeSlider, RangeSliderValue } from "./RangeSlider";
+import { Select } from "./Select";
+import { Switch } from "./Switch";
+import { Textarea } from "./Textarea";
+import { TextInput } from "./TextInput";
+
+const EVENT_TYPES: UniChatEvent["type"][] = [
+    "unichat:clear",
+    "unichat:remove_message",
+    "unichat:remove_author",
+    "unichat:message",
+    "unichat:donate",
+    "unichat:sponsor",
+    "unichat:sponsor_gift",
+    "unichat:raid",
+    "unichat:redemption",
+    "unichat:gift",
+    "unichat:userstore_update",
+    "unichat:custom"
+];
+
+const COMMON_FONTS = ["Arial", "Courier New", "Georgia", "Roboto", "Tahoma", "Times New Roman", "Verdana"];
+
+export interface FieldInputProps {
+    fieldKey: string;
+    builder: WidgetFields;
+    /** Stored value, the field default is used when missing. */
+    value: unknown;
+    onChange: (value: unknown) => void;
+}
+
+export function FieldInput({ builder, fieldKey, onChange, ...props }: FieldInputProps): PReact.ComponentChildren {
+    const value = props.value ?? ("value" in builder ? builder.value : null);
+
+    switch (builder.type) {
+        case "checkbox":
+            return (
+                <Switch
+                    key={fieldKey}
+                    label={builder.label}
+                    description={builder.description}
+                    checked={value as boolean}
+                    onChange={(evt) => onChange(evt.currentTarget.checked)}
+                />
+            );
+        case "colorpicker":
+            return (
+                <ColorPicker
+                    key={fieldKey}
+                    label={builder.label}
+                    description={builder.description}
+                    value={value as string}
+                    swatches={builder.swatches ?? []}
+                    onChange={(evt) => onChange(evt.currentTarget.value)}
+                />
+            );
+        case "dropdown": {
+            return (
+                <Select
+                    key={fieldKey}
+                    label={builder.label}
+                    description={builder.description}
+                    value={value as string}
+                    onChange={(evt) => onChange(evt.currentTarget.value)}
+                    options={Object.entries(builder.options).map(([value, label]) => ({ value, label }))}
+                />
+            );
+        }
+        case "number":
+            return (
+                <NumberInput
+                    key={fieldKey}
+                    label={builder.label}
+                    description={builder.description}
+                    min={builder.min}
+                    max={builder.max}
+                    step={builder.step}
+                    value={value as number | undefined}
+                    onChange={(evt) => onChange(evt.currentTarget.value)}
+                />
+            );
+        case "textarea":
+            return (
+                <Textarea
+                    key={fieldKey}
+                    label={builder.label}
+                    description={builder.description}
+                    value={value as string | undefined}
+                    rows={3}
+                    onChange={(evt) => onChange(evt.currentTarget.value)}
+                />
+            );
+        case "filepicker":
+            return (
+                <GalleryFileInput
+                    label={builder.label}
+                    description={builder.description}
+                    value={value as string | undefined}
+                    onChange={(evt) => onChange(evt.currentTarget.value)}
+                    showTabs={builder.fileType}
+                />
+            );
+        case "gallery":
+            return (
+                <GalleryFileInput
+                    key={fieldKey}
+                    label={builder.label}
+                    description={builder.description}
+                    value={Strings.isNullOrEmpty(value as string) ? "" : `/gallery/${encodeURIComponent(value as string)}`}
+                    onChange={(evt) => {
+                        const fileName = decodeURIComponent(evt.currentTarget.value.replace(/^\/gallery\//, ""));
+                        onChange(fileName);
+                    }}
+                    showTabs={builder.fileType}
+                />
+            );
+        case "font":
+            return (
+                <>
+                    <TextInput
+                        key={fieldKey}
+                        label={builder.label}
+                        description={builder.description}
+                        value={value as string | undefined}
+                        list={`${fieldKey}-fonts`}
+                        onChange={(evt) => onChange(evt.currentTarget.value)}
+                    />
+                    <datalist id={`${fieldKey}-fonts`}>
+                        {(builder.fonts ?? COMMON_FONTS).map((font) => (
+                            <option key={font} value={font} />
+                        ))}
+                    </datalist>
+                </>
+            );
+        case "eventtypes": {
+            const selected = Array.isArray(value) ? (value as string[]) : [];
+
+            return (
+                <FormGroup key={fieldKey} label={builder.label} description={builder.description}>
+                    {(builder.options ?? EVENT_TYPES).map((eventType) => (
+                        <Switch
+                            key={eventType}
+                            label={eventType}
+                            checked={selected.includes(eventType)}
+                            onChange={(evt) => {
+                                const checked = evt.currentTarget.checked;
+                                onChange(checked ? [...selected, eventType] : selected.filter((type) => type !== eventType));
+                            }}
+                        />
+                    ))}
+                </FormGroup>
+            );
+        }
+        case "range": {
+            const range = (value as RangeSliderValue | null) ?? { min: builder.min, max: builder.max };
+
+            return (
+                <RangeSlider
+                    key={fieldKey}
+                    label={builder.label}
+                    description={builder.description}
+                    min={builder.min}
+                    max={builder.max}
+                    step={builder.step}
+                    value={range}
+                    onChange={onChange}
+                />
+            );
+        }
+        case "json":
+            return (
+                <Textarea
+                    key={fieldKey}
+                    label={builder.label}
+                    description={builder.description}
+                    value={typeof value === "string" ? value : JSON.stringify(value ?? null, null, 2)}
+                    rows={5}
+                    onChange={(evt) => onChange(evt.currentTarget.value)}
+                />
+            );
+        case "divider":
+            return (
+                <div key={fieldKey} className="divider-wrapper">
+                    <hr />
+                    {builder.label && <span>{builder.label}</span>}
+                </div>
+            );
+        default:
+            return (
+                <TextInput
+                    key={fieldKey}
+                    label={builder.label}
+                    description={builder.description}
+                    value={value as string | undefined}
+                    onChange={(evt) => onChange(evt.currentTarget.value)}
+                />
+            );
+    }
+}
@@ -0,0 +1,66 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+import * as PReact from "preact";
+
+import { FormGroup, FormGroupBaseProps } from "unichat/components/forms/FormGroup";
+
+import { StyledRangeSliderWrapper } from "./styled";
+
+export interface RangeSliderValue {
+    min: number;
+    max: number;
+}
+
+export interface RangeSliderProps extends FormGroupBaseProps {
+    id?: string;
+    min: number;
+    max: number;
+    step?: number;
+    value: RangeSliderValue;
+    onChange: (value: RangeSliderValue) => void;
+}
+
+export function RangeSlider({ id, min, max, step, value, onChange, ...formGroupProps }: RangeSliderProps): PReact.ComponentChildren {
+    const span = max - min || 1;
+    const lowPercent = ((value.min - min) / span) * 100;
+    const highPercent = ((value.max - min) / span) * 100;
+
+    return (
+        <FormGroup id={id} {...formGroupProps}>
+            <StyledRangeSliderWrapper className="RangeSlider-container">
+                <div className="RangeSlider-track">
+                    <div className="RangeSlider-fill" style={{ left: `${lowPercent}%`, width: `${highPercent - lowPercent}%` }} />
+                </div>
+                <input
+                    type="range"
+                    aria-label="Minimum"
+                    min={min}
+                    max={max}
+                    step={step}
+                    value={value.min}
+                    onInput={(evt) => onChange({ min: Math.min(Number(evt.currentTarget.value), value.max), max: value.max })}
+                />
+                <input
+                    type="range"
+                    aria-label="Maximum"
+                    min={min}
+                    max={max}
+                    step={step}
+                    value={value.max}
+                    onInput={(evt) => onChange({ min: value.min, max: Math.max(Number(evt.currentTarget.value), value.min) })}
+                />
+                <small className="RangeSlider-values">
+                    {value.min} - {value.max}
+                </small>
+            </StyledRangeSliderWrapper>
+        </FormGroup>
+    );
+}
@@ -0,0 +1,55 @@
+/*!******************************************************************************
+ * Copyright (c) 2026 Voguh
+ *
+ * This program and the accompanying materials are made
+ * available under the terms of the Eclipse Public License 2.0
+ * which is available at https://www.eclipse.org/legal/epl-2.0/
+ *
+ * SPDX-License-Identifier: EPL-2.0
+ ******************************************************************************/
+
+import { ComponentType, HTMLAttributes } from "preact";
+
+import { styled } from "goober";
+import tw from "twin.macro";
+
+const thumbStyle = {
+    ...tw`bg-white border-0 rounded-full cursor-pointer`,
+    pointerEvents: "auto",
+    width: "14px",
+    height: "14px"
+};
+
+/** Both inputs overlap the same track, only their thumbs receive pointer events. */
+export const StyledRangeSliderWrapper: ComponentType<HTMLAttributes<HTMLDivElement>> = styled.div({
+    ...tw`relative w-full`,
+    height: "36px",
+
+    "> .RangeSlider-track": {
+        ...tw`absolute w-full bg-stone-800/90 border border-stone-800 rounded`,
+        top: "8px",
+        height: "6px",
+
+        "> .RangeSlider-fill": {
+            ...tw`absolute h-full bg-blue-600`
+        }
+    },
+
+    "> input": {
+        ...tw`absolute w-full m-0 bg-transparent appearance-none outline-none`,
+        pointerEvents: "none",
+        top: "4px",
+        height: "14px",
+
+        "&::-webkit-slider-thumb": {
+            ...thumbStyle,
+            appearance: "none"
+        },
+
+        "&::-moz-range-thumb": thumbStyle
+    },
+
+    "> .RangeSlider-values": {
+        ...tw`absolute right-0 bottom-0 text-sm text-stone-500`
+    }
+});
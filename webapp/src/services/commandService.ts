@@ -13,10 +13,15 @@ import { invoke as tauriInvoke } from "@tauri-apps/api/core";
 import { UniChatEvent } from "unichat-widgets/unichat";
 import {
     GalleryItem,
+    UniChatCatalogItem,
+    UniChatCatalogItemKind,
     UniChatCurrency,
     UniChatPluginMetadata,
     UniChatScraper,
     UniChatWidget,
+    UniChatWidgetArchivePreview,
+    UniChatWidgetForkChange,
+    UniChatWidgetPresetImport,
     WidgetFields,
     ThirdPartyLicenseInfo,
     UniChatReleaseInfo
@@ -59,6 +64,15 @@ export class CommandService {
         return invoke<UniChatCurrency[]>("get_currencies");
     }
 
+    public async getCatalog(force = false): Promise<UniChatCatalogItem[]> {
+        return invoke<UniChatCatalogItem[]>("get_catalog", { force });
+    }
+
+    /** `replace` confirms replacing a widget or plugin with the same name that was not installed from the catalog. */
+    public async installCatalogItem(kind: UniChatCatalogItemKind, name: string, replace = false): Promise<UniChatCatalogItem> {
+        return invoke<UniChatCatalogItem>("install_catalog_item", { kind, name, replace });
+    }
+
     public async getThirdPartyLicenses(): Promise<ThirdPartyLicenseInfo[]> {
         return invoke("get_third_party_licenses");
     }
@@ -85,8 +99,32 @@ export class CommandService {
         return invoke("get_plugins");
     }
 
-    public async togglePluginState(pluginName: string, newState: boolean): Promise<void> {
-        await invoke("toggle_plugin_state", { pluginName, newState });
+    public async togglePluginState(pluginName: string, newState: boolean): Promise<UniChatPluginMetadata> {
+        return invoke("toggle_plugin_state", { pluginName, newState });
+    }
+
+    public async reloadPlugin(pluginName: string): Promise<UniChatPluginMetadata> {
+        return invoke("reload_plugin", { pluginName });
+    }
+
+    public async unloadPlugin(pluginName: string): Promise<void> {
+        await invoke("unload_plugin", { pluginName });
+    }
+
+    public async acknowledgePluginPermissions(pluginName: string): Promise<void> {
+        await invoke("acknowledge_plugin_permissions", { pluginName });
+    }
+
+    public async getPluginSettingFields(pluginName: string): Promise<Record<string, WidgetFields>> {
+        return invoke("get_plugin_setting_fields", { pluginName });
+    }
+
+    public async getPluginSettings(pluginName: string): Promise<Record<string, unknown>> {
+        return invoke("get_plugin_settings", { pluginName });
+    }
+
+    public async setPluginSettings(pluginName: string, settings: Record<string, unknown>): Promise<Record<string, unknown>> {
+        return invoke("set_plugin_settings", { pluginName, settings });
     }
 
     /* ========================================================================================== */
@@ -143,13 +181,61 @@ export class CommandService {
         return invoke<Record<string, WidgetFields>>("get_widget_fields", { widget });
     }
 
-    public async getWidgetFieldState(widget: string): Promise<Record<string, any>> {
-        return invoke<Record<string, any>>("get_widget_fieldstate", { widget });
+    public async getWidgetFieldState(widget: string, instance?: string): Promise<Record<string, any>> {
+        return invoke<Record<string, any>>("get_widget_fieldstate", { widget, instance });
     }
 
-    public async setWidgetFieldState(widget: string, fieldstate: Record<string, any>): Promise<void> {
+    public async setWidgetFieldState(widget: string, fieldstate: Record<string, any>, instance?: string): Promise<void> {
         const data = JSON.stringify(fieldstate);
-        await invoke("set_widget_fieldstate", { widget, data });
+        await invoke("set_widget_fieldstate", { widget, instance, data });
+    }
+
+    public async resetWidgetFieldState(widget: string, instance?: string, fields?: string[]): Promise<string[]> {
+        return invoke<string[]>("reset_widget_fieldstate", { widget, instance, fields });
+    }
+
+    public async exportWidgetPreset(widget: string, path: string, instance?: string): Promise<void> {
+        await invoke("export_widget_preset", { widget, instance, path });
+    }
+
+    public async importWidgetPreset(widget: string, path: string, instance?: string): Promise<UniChatWidgetPresetImport> {
+        return invoke<UniChatWidgetPresetImport>("import_widget_preset", { widget, instance, path });
+    }
+
+    public async getWidgetInstances(widget: string): Promise<string[]> {
+        return invoke<string[]>("get_widget_instances", { widget });
+    }
+
+    public async createWidgetInstance(widget: string, instance: string, copyFrom?: string): Promise<void> {
+        await invoke("create_widget_instance", { widget, instance, copyFrom });
+    }
+
+    public async renameWidgetInstance(widget: string, instance: string, newName: string): Promise<void> {
+        await invoke("rename_widget_instance", { widget, instance, newName });
+    }
+
+    public async deleteWidgetInstance(widget: string, instance: string): Promise<void> {
+        await invoke("delete_widget_instance", { widget, instance });
+    }
+
+    public async exportWidget(widget: string, path: string, includeFieldstate: boolean): Promise<void> {
+        await invoke("export_widget", { widget, path, includeFieldstate });
+    }
+
+    public async previewWidgetArchive(path: string): Promise<UniChatWidgetArchivePreview> {
+        return invoke<UniChatWidgetArchivePreview>("preview_widget_archive", { path });
+    }
+
+    public async importWidgetArchive(path: string, name?: string, overwrite = false): Promise<UniChatWidget> {
+        return invoke<UniChatWidget>("import_widget_archive", { path, name, overwrite });
+    }
+
+    public async forkWidget(widget: string, name: string, overwrite = false): Promise<UniChatWidget> {
+        return invoke<UniChatWidget>("fork_widget", { widget, name, overwrite });
+    }
+
+    public async getWidgetForkChanges(widget: string): Promise<UniChatWidgetForkChange[]> {
+        return invoke<UniChatWidgetForkChange[]>("get_widget_fork_changes", { widget });
     }
 
     public async getWidgets(): Promise<UniChatWidget[]> {
@@ -21,6 +21,7 @@ export enum UniChatSettingsKeys {
     DEFAULT_PREVIEW_WIDGET = "default-preview-widget",
     OPEN_TO_LAN = "open-to-lan",
     CURRENCY_TARGET = "currency-target",
+    CATALOG_URL = "catalog-url",
 
     /* Developers settings */
     CREATE_WEBVIEW_HIDDEN = "create-webview-hidden",
@@ -37,6 +38,7 @@ export interface UniChatSettings {
     [UniChatSettingsKeys.DEFAULT_PREVIEW_WIDGET]: string;
     [UniChatSettingsKeys.OPEN_TO_LAN]: boolean;
     [UniChatSettingsKeys.CURRENCY_TARGET]: string;
+    [UniChatSettingsKeys.CATALOG_URL]: string;
 
     /* Developers settings */
     [UniChatSettingsKeys.CREATE_WEBVIEW_HIDDEN]: boolean;
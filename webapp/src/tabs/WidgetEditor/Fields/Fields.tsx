@@ -13,13 +13,7 @@ import { useEffect, useState } from "preact/hooks";
 
 import { AccordionItem } from "unichat/components/AccordionItem";
 import { Button } from "unichat/components/Button";
-import { ColorPicker } from "unichat/components/forms/ColorPicker";
-import { GalleryFileInput } from "unichat/components/forms/GalleryFileInput";
-import { NumberInput } from "unichat/components/forms/NumberInput";
-import { Select } from "unichat/components/forms/Select";
-import { Switch } from "unichat/components/forms/Switch";
-import { Textarea } from "unichat/components/forms/Textarea";
-import { TextInput } from "unichat/components/forms/TextInput";
+import { FieldInput } from "unichat/components/forms/FieldInput";
 import { LoggerFactory } from "unichat/logging/LoggerFactory";
 import { commandService } from "unichat/services/commandService";
 import { UniChatWidget, WidgetFields } from "unichat/types";
@@ -33,10 +27,12 @@ interface Props {
     selectedWidget: string;
     handleReset: () => Promise<void>;
     handleApply: (values: Record<string, unknown>) => Promise<void>;
+    handleExportPreset: () => Promise<void>;
+    handleImportPreset: () => Promise<void>;
 }
 
 const _logger = LoggerFactory.getLogger("Fields");
-export function Fields({ handleApply, handleReset, selectedWidget, widgets }: Props): PReact.ComponentChildren {
+export function Fields({ handleApply, handleExportPreset, handleImportPreset, handleReset, selectedWidget, widgets }: Props): PReact.ComponentChildren {
     const [fields, setFields] = useState<Record<string, WidgetFields> | null>(null);
     const [fieldState, setFieldState] = useState<Record<string, unknown> | null>(null);
     const [openedItem, setOpenedItem] = useState<string | null>(null);
@@ -46,94 +42,15 @@ export function Fields({ handleApply, handleReset, selectedWidget, widgets }: Pr
             return null;
         }
 
-        const value = fieldState[key] ?? ("value" in builder ? builder.value : null);
-
-        switch (builder.type) {
-            case "checkbox":
-                return (
-                    <Switch
-                        key={key}
-                        label={builder.label}
-                        description={builder.description}
-                        checked={value as boolean}
-                        onChange={(evt) => setFieldState((old) => ({ ...old, [key]: evt.currentTarget.checked }))}
-                    />
-                );
-            case "colorpicker":
-                return (
-                    <ColorPicker
-                        key={key}
-                        label={builder.label}
-                        description={builder.description}
-                        value={value as string}
-                        swatches={builder.swatches ?? []}
-                        onChange={(evt) => setFieldState((old) => ({ ...old, [key]: evt.currentTarget.value }))}
-                    />
-                );
-            case "dropdown": {
-                return (
-                    <Select
-                        key={key}
-                        label={builder.label}
-                        description={builder.description}
-                        value={value as string}
-                        onChange={(evt) => setFieldState((old) => ({ ...old, [key]: evt.currentTarget.value }))}
-                        options={Object.entries(builder.options).map(([value, label]) => ({ value, label }))}
-                    />
-                );
-            }
-            case "number":
-                return (
-                    <NumberInput
-                        key={key}
-                        label={builder.label}
-                        description={builder.description}
-                        min={builder.min}
-                        max={builder.max}
-                        step={builder.step}
-                        value={value as number | undefined}
-                        onChange={(evt) => setFieldState((old) => ({ ...old, [key]: evt.currentTarget.value }))}
-                    />
-                );
-            case "textarea":
-                return (
-                    <Textarea
-                        key={key}
-                        label={builder.label}
-                        description={builder.description}
-                        value={value as string | undefined}
-                        rows={3}
-                        onChange={(evt) => setFieldState((old) => ({ ...old, [key]: evt.currentTarget.value }))}
-                    />
-                );
-            case "filepicker":
-                return (
-                    <GalleryFileInput
-                        label={builder.label}
-                        description={builder.description}
-                        value={value as string | undefined}
-                        onChange={(evt) => setFieldState((old) => ({ ...old, [key]: evt.currentTarget.value }))}
-                        showTabs={builder.fileType}
-                    />
-                );
-            case "divider":
-                return (
-                    <div key={key} className="divider-wrapper">
-                        <hr />
-                        {builder.label && <span>{builder.label}</span>}
-                    </div>
-                );
-            default:
-                return (
-                    <TextInput
-                        key={key}
-                        label={builder.label}
-                        description={builder.description}
-                        value={value as string | undefined}
-                        onChange={(evt) => setFieldState((old) => ({ ...old, [key]: evt.currentTarget.value }))}
-                    />
-                );
-        }
+        return (
+            <FieldInput
+                key={key}
+                fieldKey={key}
+                builder={builder}
+                value={fieldState[key]}
+                onChange={(newValue) => setFieldState((old) => ({ ...old, [key]: newValue }))}
+            />
+        );
     }
 
     function buildFieldsEditor(): PReact.ComponentChildren {
@@ -195,6 +112,11 @@ export function Fields({ handleApply, handleReset, selectedWidget, widgets }: Pr
         await handleFetchWidgetData();
     }
 
+    async function wrappedImportPreset(): Promise<void> {
+        await handleImportPreset();
+        await handleFetchWidgetData();
+    }
+
     useEffect(() => {
         handleFetchWidgetData();
     }, [selectedWidget]);
@@ -205,6 +127,12 @@ export function Fields({ handleApply, handleReset, selectedWidget, widgets }: Pr
                 <span>Fields Editor</span>
                 {fields != null && Object.keys(fields).length > 0 && (
                     <div className="fields--actions">
+                        <Button variant="default" onClick={() => handleExportPreset()}>
+                            <i className="fas fa-file-export" />
+                        </Button>
+                        <Button variant="default" onClick={() => wrappedImportPreset()}>
+                            <i className="fas fa-file-import" />
+                        </Button>
                         <Button variant="default" onClick={() => wrappedReset()}>
                             <i className="fas fa-undo" />
                         </Button>
@@ -8,6 +8,7 @@
  * SPDX-License-Identifier: EPL-2.0
  ******************************************************************************/
 
+import * as dialog from "@tauri-apps/plugin-dialog";
 import * as PReact from "preact";
 import { useRef, useState } from "preact/hooks";
 
@@ -69,7 +70,7 @@ export function WidgetEditor(): PReact.ComponentChildren {
     async function handleReset(): Promise<void> {
         try {
             if (!Strings.isNullOrEmpty(selectedWidget)) {
-                await commandService.setWidgetFieldState(selectedWidget, {});
+                await commandService.resetWidgetFieldState(selectedWidget);
                 reloadIframe();
                 notificationService.success({ title: "Success", message: "Widget field state reset." });
             }
@@ -82,6 +83,53 @@ export function WidgetEditor(): PReact.ComponentChildren {
         }
     }
 
+    async function handleExportPreset(): Promise<void> {
+        try {
+            const path = await dialog.save({
+                defaultPath: `${selectedWidget.replace("::", "-")}.ucpreset`,
+                filters: [{ name: "UniChat Widget Preset", extensions: ["ucpreset"] }]
+            });
+            if (path == null) {
+                return;
+            }
+
+            await commandService.exportWidgetPreset(selectedWidget, path);
+            notificationService.success({ title: "Success", message: "Widget preset exported." });
+        } catch (err) {
+            _logger.error("An error occurred on export widget preset", err);
+            notificationService.error({
+                title: "Error",
+                message: `Failed to export widget preset: ${(err as Error).message}`
+            });
+        }
+    }
+
+    async function handleImportPreset(): Promise<void> {
+        try {
+            const path = await dialog.open({
+                multiple: false,
+                directory: false,
+                filters: [{ name: "UniChat Widget Preset", extensions: ["ucpreset"] }]
+            });
+            if (path == null || Array.isArray(path)) {
+                return;
+            }
+
+            const result = await commandService.importWidgetPreset(selectedWidget, path);
+            reloadIframe();
+            notificationService.success({
+                title: "Success",
+                message: `Applied ${result.applied.length} field(s) from preset.${result.warnings.length > 0 ? ` ${result.warnings.join("; ")}.` : ""}`
+            });
+        } catch (err) {
+            _logger.error("An error occurred on import widget preset", err);
+            notificationService.error({
+                title: "Error",
+                message: `Failed to import widget preset: ${(err as Error).message}`
+            });
+        }
+    }
+
     return (
         <WidgetEditorStyledContainer>
             <div className="widget_editor--header">
@@ -103,6 +151,8 @@ export function WidgetEditor(): PReact.ComponentChildren {
                     <Fields
                         handleApply={handleApply}
                         handleReset={handleReset}
+                        handleExportPreset={handleExportPreset}
+                        handleImportPreset={handleImportPreset}
                         selectedWidget={selectedWidget}
                         widgets={widgets}
                     />
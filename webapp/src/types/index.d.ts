@@ -8,6 +8,8 @@
  * SPDX-License-Identifier: EPL-2.0
  ******************************************************************************/
 
+import { UniChatEvent } from "unichat-widgets/unichat";
+
 import { PluginStatus, WidgetSourceType } from "unichat/utils/constants";
 
 export interface Dimensions {
@@ -75,6 +77,10 @@ export interface UniChatPluginMetadata {
     license?: string;
     homepage?: string;
     dependencies: string[];
+    permissions: string[];
+    pendingPermissions: string[];
+    hasSettings: boolean;
+    limitFailures: number;
 
     icon?: string;
     status: PluginStatus;
@@ -99,6 +105,71 @@ export interface UniChatScraper {
 export interface UniChatWidget {
     restPath: string;
     widgetSource: UniChatWidgetSource;
+    manifest?: UniChatWidgetManifest;
+    previewUrl?: string;
+    fork?: UniChatWidgetFork;
+    warnings: string[];
+}
+
+export interface UniChatWidgetFork {
+    source: string;
+    sourceVersion?: string;
+    forkedAt: number;
+    sourceMissing: boolean;
+    sourceChanged: boolean;
+}
+
+export type UniChatWidgetFileChange = "UNCHANGED" | "ADDED" | "MODIFIED" | "REMOVED";
+
+export interface UniChatWidgetForkChange {
+    path: string;
+    source: UniChatWidgetFileChange;
+    fork: UniChatWidgetFileChange;
+}
+
+export interface UniChatWidgetManifest {
+    name?: string;
+    description?: string;
+    version?: string;
+    author?: string;
+    license?: string;
+    homepage?: string;
+    preview?: string;
+    unichat?: string;
+}
+
+export interface UniChatWidgetArchivePreview {
+    name: string;
+    suggestedName: string;
+    conflict: boolean;
+    unichatVersion: string;
+    manifest?: UniChatWidgetManifest;
+    files: string[];
+    size: number;
+    hasFieldstate: boolean;
+    instances: string[];
+    warnings: string[];
+}
+
+export type UniChatCatalogItemKind = "WIDGET" | "PLUGIN";
+
+export interface UniChatCatalogItem {
+    kind: UniChatCatalogItemKind;
+    name: string;
+    description?: string;
+    version: string;
+    author?: string;
+    homepage?: string;
+    unichat?: string;
+    download: string;
+    installedVersion?: string;
+    installedFromCatalog: boolean;
+    hasUpdate: boolean;
+    compatible: boolean;
+}
+
+export interface UniChatWidgetPresetImport {
+    applied: string[];
     warnings: string[];
 }
 
@@ -163,6 +234,52 @@ export interface WidgetFieldsFilePicker {
     fileType: ("image" | "video" | "audio" | "file")[];
 }
 
+export interface WidgetFieldsGallery {
+    type: "gallery";
+    group?: string;
+    label: string;
+    description?: string;
+    value?: string;
+    fileType?: ("image" | "video" | "audio" | "file")[];
+}
+
+export interface WidgetFieldsFont {
+    type: "font";
+    group?: string;
+    label: string;
+    description?: string;
+    value?: string;
+    fonts?: string[];
+}
+
+export interface WidgetFieldsEventTypes {
+    type: "eventtypes";
+    group?: string;
+    label: string;
+    description?: string;
+    value?: UniChatEvent["type"][];
+    options?: UniChatEvent["type"][];
+}
+
+export interface WidgetFieldsRange {
+    type: "range";
+    group?: string;
+    label: string;
+    description?: string;
+    value?: { min: number; max: number };
+    min: number;
+    max: number;
+    step?: number;
+}
+
+export interface WidgetFieldsJson {
+    type: "json";
+    group?: string;
+    label: string;
+    description?: string;
+    value?: unknown;
+}
+
 export interface WidgetFieldsDivider {
     type: "divider";
     group?: string;
@@ -176,6 +293,11 @@ export type WidgetFields =
     | WidgetFieldsColorPicker
     | WidgetFieldsDropdown
     | WidgetFieldsFilePicker
+    | WidgetFieldsGallery
+    | WidgetFieldsFont
+    | WidgetFieldsEventTypes
+    | WidgetFieldsRange
+    | WidgetFieldsJson
     | WidgetFieldsDivider;
 
 /* ========================================================================== */
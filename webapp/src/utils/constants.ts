@@ -30,12 +30,14 @@ export function scraperPriority(id: string): number {
  * - INVALID: The plugin is invalid due to missing or incorrect dependencies or structure.
  * - ACTIVE: The plugin is active and running.
  * - ERROR: The plugin encountered an error during loading or execution.
+ * - DISABLED: The plugin was disabled by the user and is not started.
  */
 export enum PluginStatus {
     LOADED = "LOADED",
     INVALID = "INVALID",
     ACTIVE = "ACTIVE",
-    ERROR = "ERROR"
+    ERROR = "ERROR",
+    DISABLED = "DISABLED"
 }
 
 export enum WidgetSourceType {